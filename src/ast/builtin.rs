@@ -0,0 +1,5 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Builtin {
+    Merge,
+    ToString,
+}
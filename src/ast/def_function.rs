@@ -6,10 +6,10 @@ use super::signature::Signature;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct DefFunction {
-    name: Str,
-    signature: Signature,
-    inner_statements: Vec<InnerStatement>,
-    body: Expression,
+    pub name: Str,
+    pub signature: Signature,
+    pub inner_statements: Vec<InnerStatement>,
+    pub body: Expression,
 }
 
 impl DefFunction {
@@ -1,16 +1,22 @@
-use super::super::core::Str;
+use super::super::core::{Bytes, Str, Symbol};
 
 use super::arguments::Arguments;
+use super::builtin::Builtin;
 use super::expansion::Expansion;
+use super::interpolation::Interpolation;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Expression {
     App(Box<Expression>, Arguments),
     Boolean(bool),
+    Builtin(Builtin),
+    Bytes(Bytes),
     Dictionary(Vec<Expansion<(Expression, Expression)>>),
+    Interpolation(Vec<Interpolation>),
     List(Vec<Expansion<Expression>>),
     Name(Str),
     Nil,
     Number(f64),
     String(Str),
+    Symbol(Symbol),
 }
@@ -0,0 +1,9 @@
+use super::super::core::Str;
+
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Interpolation {
+    Expression(Expression),
+    String(Str),
+}
@@ -4,8 +4,8 @@ use super::expression::Expression;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct KeywordArgument {
-    name: Str,
-    value: Expression,
+    pub name: Str,
+    pub value: Expression,
 }
 
 impl KeywordArgument {
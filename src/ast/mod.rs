@@ -1,27 +1,33 @@
 mod arguments;
+mod builtin;
 mod def_function;
 mod effect;
 mod expansion;
 mod expression;
 mod import;
 mod inner_statement;
+mod interpolation;
 mod keyword_argument;
 mod let_variable;
 mod module;
 mod optional_parameter;
+mod pragma;
 mod signature;
 mod statement;
 
 pub use self::arguments::Arguments;
+pub use self::builtin::Builtin;
 pub use self::def_function::DefFunction;
 pub use self::effect::Effect;
 pub use self::expansion::Expansion;
 pub use self::expression::Expression;
 pub use self::import::Import;
 pub use self::inner_statement::InnerStatement;
+pub use self::interpolation::Interpolation;
 pub use self::keyword_argument::KeywordArgument;
 pub use self::let_variable::LetVariable;
 pub use self::module::Module;
 pub use self::optional_parameter::OptionalParameter;
+pub use self::pragma::Pragma;
 pub use self::signature::{HalfSignature, Signature};
 pub use self::statement::Statement;
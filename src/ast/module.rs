@@ -1,15 +1,18 @@
 use super::import::Import;
+use super::pragma::Pragma;
 use super::statement::Statement;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Module {
+    pub pragmas: Vec<Pragma>,
     pub imports: Vec<Import>,
     pub statements: Vec<Statement>,
 }
 
 impl Module {
-    pub fn new(imports: Vec<Import>, statements: Vec<Statement>) -> Self {
+    pub fn new(pragmas: Vec<Pragma>, imports: Vec<Import>, statements: Vec<Statement>) -> Self {
         Module {
+            pragmas,
             imports,
             statements,
         }
@@ -4,8 +4,8 @@ use super::super::core::Str;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct OptionalParameter {
-    name: Str,
-    value: Expression,
+    pub name: Str,
+    pub value: Expression,
 }
 
 impl OptionalParameter {
@@ -0,0 +1,12 @@
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pragma(String);
+
+impl Pragma {
+    pub fn new(s: String) -> Self {
+        Pragma(s)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
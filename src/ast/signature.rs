@@ -4,8 +4,8 @@ use super::optional_parameter::OptionalParameter;
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Signature {
-    positionals: HalfSignature,
-    keywords: HalfSignature,
+    pub positionals: HalfSignature,
+    pub keywords: HalfSignature,
 }
 
 impl Signature {
@@ -19,9 +19,9 @@ impl Signature {
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct HalfSignature {
-    requireds: Vec<Str>,
-    optionals: Vec<OptionalParameter>,
-    rest: Str,
+    pub requireds: Vec<Str>,
+    pub optionals: Vec<OptionalParameter>,
+    pub rest: Str,
 }
 
 impl HalfSignature {
@@ -0,0 +1,74 @@
+use std::fs;
+
+use super::super::core::{Bytes, OptionalParameter, Result, Signature, Value};
+
+impure_function!(
+    READ_FILE,
+    Signature::new(
+        vec!["path".into()],
+        "".into(),
+        vec![OptionalParameter::new("binary", false)],
+        "".into()
+    ),
+    read_file
+);
+
+async fn read_file(vs: Vec<Value>) -> Result {
+    let p = await!(vs[0].clone().string())?;
+    let bs: Bytes = fs::read(p.as_str())?.into();
+
+    Ok(if await!(vs[1].clone().boolean())? {
+        bs.into()
+    } else {
+        bs.to_str()?.into()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::env::temp_dir;
+
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::super::core::{app, papp, Arguments, Expansion, KeywordArgument};
+
+    fn read(p: &str, b: bool) -> Value {
+        app(
+            READ_FILE.clone(),
+            Arguments::new(
+                &[Expansion::Unexpanded(p.into())],
+                &[Expansion::Unexpanded(KeywordArgument::new("binary", b))],
+            ),
+        )
+    }
+
+    #[test]
+    fn read_file() {
+        let p = temp_dir().join("flame_read_file_test");
+        let p = p.to_str().unwrap();
+
+        fs::write(p, b"foo\xff").unwrap();
+
+        let v: Value = block_on_stable(read(p, true).impure()).unwrap().into();
+        assert!(block_on_stable(v.equal(Bytes::new(b"foo\xff").into())).unwrap());
+
+        let e = block_on_stable(read(p, false).impure()).unwrap_err();
+        assert_eq!(e.name(), "ValueError");
+
+        fs::write(p, "日本語").unwrap();
+
+        let v: Value = block_on_stable(read(p, false).impure()).unwrap().into();
+        assert!(block_on_stable(v.equal("日本語".into())).unwrap());
+
+        fs::remove_file(p).unwrap();
+    }
+
+    #[test]
+    fn read_file_error() {
+        for v in vec!["/non/existent/file".into(), 42.into()]: Vec<Value> {
+            assert!(block_on_stable(papp(READ_FILE.clone(), &[v]).impure()).is_err());
+        }
+    }
+}
@@ -1,7 +1,18 @@
+mod file;
 mod list;
+mod process;
+mod random;
+mod time;
+mod timer;
 mod write;
 mod y;
 
+pub use self::file::READ_FILE;
 pub use self::list::LIST;
+pub use self::process::RUN_COMMAND;
+pub use self::random::{
+    seed as seed_random, CHOOSE, RANDOM, RANDOM_INTEGER, RANDOM_NEXT, RANDOM_SPLIT, SHUFFLE,
+};
+pub use self::time::{MONOTONIC, NOW, SLEEP};
 pub use self::write::WRITE;
 pub use self::y::Y;
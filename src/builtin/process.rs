@@ -0,0 +1,96 @@
+use std::process::Command;
+
+use super::super::core::{Bytes, Error, OptionalParameter, Result, Signature, Value};
+
+impure_function!(
+    RUN_COMMAND,
+    Signature::new(
+        vec!["command".into()],
+        "arguments".into(),
+        vec![OptionalParameter::new("binary", false)],
+        "".into()
+    ),
+    run_command
+);
+
+async fn run_command(vs: Vec<Value>) -> Result {
+    let c = await!(vs[0].clone().string())?;
+    let mut l = await!(vs[1].clone().list())?;
+    let mut ss = vec![];
+
+    while !l.is_empty() {
+        ss.push(await!(l.first()?.string())?);
+        l = await!(l.rest())?;
+    }
+
+    let o = Command::new(c.as_str())
+        .args(ss.iter().map(|s| s.as_str()))
+        .output()?;
+
+    if !o.status.success() {
+        return Err(Error::runtime(&format!(
+            "command {:?} failed with {}: {}",
+            c.as_str(),
+            o.status,
+            String::from_utf8_lossy(&o.stderr).trim()
+        )));
+    }
+
+    let bs: Bytes = o.stdout.into();
+
+    Ok(if await!(vs[2].clone().boolean())? {
+        bs.into()
+    } else {
+        bs.to_str()?.into()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::super::core::{app, papp, Arguments, Expansion, KeywordArgument};
+
+    #[test]
+    fn run_command() {
+        let v: Value = block_on_stable(
+            papp(RUN_COMMAND.clone(), &["echo".into(), "foo".into(), "bar".into()]).impure(),
+        ).unwrap()
+            .into();
+
+        assert!(block_on_stable(v.equal("foo bar\n".into())).unwrap());
+
+        let v: Value = block_on_stable(
+            app(
+                RUN_COMMAND.clone(),
+                Arguments::new(
+                    &[
+                        Expansion::Unexpanded("printf".into()),
+                        Expansion::Unexpanded("\\377".into()),
+                    ],
+                    &[Expansion::Unexpanded(KeywordArgument::new("binary", true))],
+                ),
+            ).impure(),
+        ).unwrap()
+            .into();
+
+        assert!(block_on_stable(v.equal(Bytes::new(b"\xff").into())).unwrap());
+    }
+
+    #[test]
+    fn run_command_error() {
+        for (vs, e) in vec![
+            (vec!["false".into()], "RuntimeError"),
+            (vec!["printf".into(), "\\377".into()], "ValueError"),
+            (vec![42.into()], "TypeError"),
+        ]: Vec<(Vec<Value>, &str)>
+        {
+            assert_eq!(
+                block_on_stable(papp(RUN_COMMAND.clone(), &vs).impure()).unwrap_err().name(),
+                e
+            );
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use std::result;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::super::core::{Error, List, Normal, OptionalParameter, Result, Signature, Value};
+
+const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+const PURE_GAMMA: u64 = 0x0013_c6ef_3720_6c3b;
+const PURE_STATE_MASK: u64 = (1 << 53) - 1;
+
+lazy_static! {
+    static ref STATE: AtomicU64 = AtomicU64::new(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() ^ u64::from(d.subsec_nanos()) << 32)
+            .unwrap_or(0)
+    );
+}
+
+pub fn seed(s: u64) {
+    STATE.store(s, Ordering::SeqCst);
+}
+
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn to_f64(u: u64) -> f64 {
+    (u >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Generator(u64);
+
+impl Generator {
+    fn new(s: u64) -> Self {
+        Generator(s)
+    }
+
+    fn global() -> Self {
+        Self::new(mix(STATE.fetch_add(GAMMA, Ordering::SeqCst).wrapping_add(GAMMA)))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(GAMMA);
+        mix(self.0)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        to_f64(self.next_u64())
+    }
+
+    fn next_below(&mut self, n: u64) -> u64 {
+        let z = u64::max_value() - u64::max_value() % n;
+
+        loop {
+            let u = self.next_u64();
+
+            if u < z {
+                return u % n;
+            }
+        }
+    }
+}
+
+fn integer(n: f64) -> result::Result<i64, Error> {
+    if n % 1.0 == 0.0 && n.abs() <= PURE_STATE_MASK as f64 {
+        Ok(n as i64)
+    } else {
+        Err(Error::value(&format!("{} is not an integer", n)))
+    }
+}
+
+fn generator(v: Value) -> result::Result<Generator, Error> {
+    Ok(match await!(v.pured())? {
+        Normal::Nil => Generator::global(),
+        Normal::Number(n) => Generator::new(mix(integer(n)? as u64)),
+        n => return Err(await!(Error::not_number(n.into()))?),
+    })
+}
+
+fn elements(v: Value) -> result::Result<Vec<Value>, Error> {
+    let mut l = await!(v.list())?;
+    let mut vs = vec![];
+
+    while !l.is_empty() {
+        vs.push(l.first()?);
+        l = await!(l.rest())?;
+    }
+
+    Ok(vs)
+}
+
+impure_function!(
+    RANDOM,
+    Signature::new(
+        vec![],
+        "".into(),
+        vec![OptionalParameter::new("seed", Value::Nil)],
+        "".into()
+    ),
+    random
+);
+
+async fn random(vs: Vec<Value>) -> Result {
+    Ok(await!(generator(vs[0].clone()))?.next_f64().into())
+}
+
+impure_function!(
+    RANDOM_INTEGER,
+    Signature::new(
+        vec!["min".into(), "max".into()],
+        "".into(),
+        vec![OptionalParameter::new("seed", Value::Nil)],
+        "".into()
+    ),
+    random_integer
+);
+
+async fn random_integer(vs: Vec<Value>) -> Result {
+    let m = integer(await!(vs[0].clone().number())?)?;
+    let n = integer(await!(vs[1].clone().number())?)?;
+
+    if m > n {
+        return Err(Error::value(&format!("range from {} to {} is empty", m, n)));
+    }
+
+    let mut g = await!(generator(vs[2].clone()))?;
+
+    Ok(((m + g.next_below((n - m + 1) as u64) as i64) as f64).into())
+}
+
+impure_function!(
+    CHOOSE,
+    Signature::new(
+        vec!["list".into()],
+        "".into(),
+        vec![OptionalParameter::new("seed", Value::Nil)],
+        "".into()
+    ),
+    choose
+);
+
+async fn choose(vs: Vec<Value>) -> Result {
+    let xs = await!(elements(vs[0].clone()))?;
+
+    if xs.is_empty() {
+        return Err(Error::empty_list());
+    }
+
+    let mut g = await!(generator(vs[1].clone()))?;
+
+    Ok(xs[g.next_below(xs.len() as u64) as usize].clone())
+}
+
+impure_function!(
+    SHUFFLE,
+    Signature::new(
+        vec!["list".into()],
+        "".into(),
+        vec![OptionalParameter::new("seed", Value::Nil)],
+        "".into()
+    ),
+    shuffle
+);
+
+async fn shuffle(vs: Vec<Value>) -> Result {
+    let mut xs = await!(elements(vs[0].clone()))?;
+    let mut g = await!(generator(vs[1].clone()))?;
+
+    for i in (1..xs.len()).rev() {
+        let j = g.next_below(i as u64 + 1) as usize;
+        xs.swap(i, j);
+    }
+
+    Ok(List::new(&xs).into())
+}
+
+fn pure_generator(v: Value) -> result::Result<u64, Error> {
+    let n = integer(await!(v.number())?)?;
+
+    if n < 0 {
+        return Err(Error::value(&format!("{} is not a random generator", n)));
+    }
+
+    Ok(n as u64)
+}
+
+pure_function!(
+    RANDOM_NEXT,
+    Signature::new(vec!["generator".into()], "".into(), vec![], "".into()),
+    random_next
+);
+
+async fn random_next(vs: Vec<Value>) -> Result {
+    let s = await!(pure_generator(vs[0].clone()))?.wrapping_add(PURE_GAMMA) & PURE_STATE_MASK;
+
+    Ok(List::new(&[to_f64(mix(s)).into(), (s as f64).into()]).into())
+}
+
+pure_function!(
+    RANDOM_SPLIT,
+    Signature::new(vec!["generator".into()], "".into(), vec![], "".into()),
+    random_split
+);
+
+async fn random_split(vs: Vec<Value>) -> Result {
+    let s = await!(pure_generator(vs[0].clone()))?;
+
+    Ok(List::new(&[
+        ((mix(s.wrapping_add(PURE_GAMMA)) & PURE_STATE_MASK) as f64).into(),
+        ((mix(s.wrapping_add(PURE_GAMMA.wrapping_mul(2))) & PURE_STATE_MASK) as f64).into(),
+    ]).into())
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::super::core::functions::EQUAL;
+    use super::super::super::core::{app, papp, Arguments, Expansion, KeywordArgument};
+
+    fn seeded(f: Value, vs: &[Value], s: f64) -> Value {
+        let ps: Vec<Expansion<Value>> = vs
+            .iter()
+            .map(|v| Expansion::Unexpanded(v.clone()))
+            .collect();
+
+        app(
+            f,
+            Arguments::new(&ps, &[Expansion::Unexpanded(KeywordArgument::new("seed", s))]),
+        )
+    }
+
+    fn number(v: Value) -> f64 {
+        match block_on_stable(v.impure()).unwrap() {
+            Normal::Number(n) => n,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn random() {
+        for s in 0..100 {
+            let x = number(seeded(RANDOM.clone(), &[], s as f64));
+
+            assert!(0.0 <= x && x < 1.0);
+            assert_eq!(number(seeded(RANDOM.clone(), &[], s as f64)), x);
+        }
+
+        let x = number(papp(RANDOM.clone(), &[]));
+        assert!(0.0 <= x && x < 1.0);
+    }
+
+    #[test]
+    fn random_integer() {
+        for s in 0..100 {
+            let x = number(seeded(
+                RANDOM_INTEGER.clone(),
+                &[(-2.0).into(), 3.into()],
+                s as f64,
+            ));
+
+            assert!(-2.0 <= x && x <= 3.0);
+            assert_eq!(x % 1.0, 0.0);
+        }
+
+        assert_eq!(
+            number(seeded(RANDOM_INTEGER.clone(), &[42.into(), 42.into()], 0.0)),
+            42.0
+        );
+    }
+
+    #[test]
+    fn random_integer_error() {
+        for vs in vec![&[1.into(), 0.into()], &[0.5.into(), 1.into()]]: Vec<&[Value]> {
+            assert!(block_on_stable(seeded(RANDOM_INTEGER.clone(), vs, 0.0).impure()).is_err());
+        }
+    }
+
+    #[test]
+    fn choose() {
+        let l: Value = List::new(&[1.into(), 2.into(), 3.into()]).into();
+
+        for s in 0..100 {
+            let x = number(seeded(CHOOSE.clone(), &[l.clone()], s as f64));
+            assert!(x == 1.0 || x == 2.0 || x == 3.0);
+        }
+
+        assert!(
+            block_on_stable(seeded(CHOOSE.clone(), &[List::Empty.into()], 0.0).impure()).is_err()
+        );
+    }
+
+    #[test]
+    fn shuffle() {
+        let l: Value = List::new(&[1.into(), 2.into(), 3.into(), 4.into()]).into();
+
+        for s in 0..100 {
+            let v: Value =
+                block_on_stable(seeded(SHUFFLE.clone(), &[l.clone()], s as f64).impure())
+                    .unwrap()
+                    .into();
+            let mut xs: Vec<f64> = block_on_stable(elements(v))
+                .unwrap()
+                .into_iter()
+                .map(|v| block_on_stable(v.number()).unwrap())
+                .collect();
+
+            xs.sort_by(|x, y| x.partial_cmp(y).unwrap());
+
+            assert_eq!(xs, vec![1.0, 2.0, 3.0, 4.0]);
+        }
+    }
+
+    #[test]
+    fn random_next() {
+        let l = papp(RANDOM_NEXT.clone(), &[42.into()]);
+
+        assert!(
+            block_on_stable(
+                papp(
+                    EQUAL.clone(),
+                    &[l.clone(), papp(RANDOM_NEXT.clone(), &[42.into()])]
+                ).boolean()
+            ).unwrap()
+        );
+
+        let xs = block_on_stable(elements(l)).unwrap();
+        let x = block_on_stable(xs[0].clone().number()).unwrap();
+
+        assert!(0.0 <= x && x < 1.0);
+        assert_ne!(block_on_stable(xs[1].clone().number()).unwrap(), 42.0);
+    }
+
+    #[test]
+    fn random_split() {
+        let xs = block_on_stable(elements(papp(RANDOM_SPLIT.clone(), &[42.into()]))).unwrap();
+
+        assert_eq!(xs.len(), 2);
+        assert!(!block_on_stable(xs[0].clone().equal(xs[1].clone())).unwrap());
+
+        let g = block_on_stable(elements(papp(RANDOM_NEXT.clone(), &[42.into()]))).unwrap()[1]
+            .clone();
+
+        for x in xs {
+            assert_ne!(randoms(g.clone(), 4), randoms(x, 4));
+        }
+    }
+
+    fn randoms(mut g: Value, n: usize) -> Vec<f64> {
+        let mut xs = vec![];
+
+        for _ in 0..n {
+            let l = block_on_stable(elements(papp(RANDOM_NEXT.clone(), &[g]))).unwrap();
+
+            xs.push(block_on_stable(l[0].clone().number()).unwrap());
+            g = l[1].clone();
+        }
+
+        xs
+    }
+
+    #[test]
+    fn pure_generator_error() {
+        for v in vec![(-1.0).into(), 0.5.into(), "foo".into()]: Vec<Value> {
+            assert!(block_on_stable(papp(RANDOM_NEXT.clone(), &[v]).pured()).is_err());
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::result;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::u64;
+
+use super::super::core::{Error, Result, Signature, Value};
+
+use super::timer;
+
+lazy_static! {
+    static ref START: Instant = Instant::now();
+}
+
+fn seconds(d: Duration) -> f64 {
+    d.as_secs() as f64 + f64::from(d.subsec_nanos()) / 1e9
+}
+
+fn duration(n: f64) -> result::Result<Duration, Error> {
+    if !n.is_finite() || n < 0.0 || n >= u64::MAX as f64 {
+        return Err(Error::value(&format!("{} is not a valid duration", n)));
+    }
+
+    Ok(Duration::new(n as u64, ((n % 1.0) * 1e9) as u32))
+}
+
+impure_function!(NOW, Signature::default(), now);
+
+async fn now(_: Vec<Value>) -> Result {
+    Ok(seconds(SystemTime::now().duration_since(UNIX_EPOCH)?).into())
+}
+
+impure_function!(MONOTONIC, Signature::default(), monotonic);
+
+async fn monotonic(_: Vec<Value>) -> Result {
+    Ok(seconds(START.elapsed()).into())
+}
+
+impure_function!(
+    SLEEP,
+    Signature::new(vec!["seconds".into()], "".into(), vec![], "".into()),
+    sleep
+);
+
+async fn sleep(vs: Vec<Value>) -> Result {
+    let n = await!(vs[0].clone().number())?;
+
+    let b = match Instant::now().checked_add(duration(n)?) {
+        Some(i) => timer::wait(i),
+        None => return Err(Error::value(&format!("{} is too long to sleep", n))),
+    };
+
+    await!(&*b)?;
+
+    Ok(Value::Nil)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::mpsc::{channel, Sender};
+    use std::task::Executor;
+    use std::time::Instant;
+
+    use futures::executor::ThreadPoolBuilder;
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::super::core::{papp, Normal};
+
+    fn number(v: Value) -> f64 {
+        match block_on_stable(v.impure()).unwrap() {
+            Normal::Number(n) => n,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn now() {
+        assert!(number(papp(NOW.clone(), &[])) > 0.0);
+    }
+
+    #[test]
+    fn monotonic() {
+        let x = number(papp(MONOTONIC.clone(), &[]));
+        let y = number(papp(MONOTONIC.clone(), &[]));
+
+        assert!(0.0 <= x && x <= y);
+    }
+
+    #[test]
+    fn sleep() {
+        let i = Instant::now();
+
+        match block_on_stable(papp(SLEEP.clone(), &[0.1.into()]).impure()).unwrap() {
+            Normal::Nil => {}
+            _ => unreachable!(),
+        }
+
+        assert!(i.elapsed() >= Duration::from_millis(100));
+    }
+
+    async fn effect(v: Value, s: Sender<&'static str>, m: &'static str) {
+        await!(v.impure()).unwrap();
+        s.send(m).unwrap();
+    }
+
+    #[test]
+    fn sleep_concurrently() {
+        let mut p = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+        let (s, r) = channel();
+
+        for (v, m) in vec![
+            (papp(SLEEP.clone(), &[0.2.into()]), "sleep"),
+            (papp(MONOTONIC.clone(), &[]), "monotonic"),
+        ] {
+            p.spawn_obj(Box::new(effect(v, s.clone(), m)).into()).unwrap();
+        }
+
+        assert_eq!(r.recv_timeout(Duration::from_millis(100)), Ok("monotonic"));
+        assert_eq!(r.recv().unwrap(), "sleep");
+    }
+
+    #[test]
+    fn sleep_error() {
+        for v in vec![(-1.0).into(), 1e19.into(), 1e20.into(), "foo".into()]: Vec<Value> {
+            assert!(block_on_stable(papp(SLEEP.clone(), &[v]).impure()).is_err());
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::spawn;
+use std::time::Instant;
+
+use super::super::core::BlackHole;
+
+lazy_static! {
+    static ref TIMER: Arc<Timer> = Timer::start();
+}
+
+pub fn wait(i: Instant) -> Arc<BlackHole> {
+    TIMER.insert(i)
+}
+
+struct Timer {
+    entries: Mutex<BinaryHeap<Entry>>,
+    condvar: Condvar,
+}
+
+impl Timer {
+    fn start() -> Arc<Self> {
+        let t = Arc::new(Timer {
+            entries: Mutex::new(BinaryHeap::new()),
+            condvar: Condvar::new(),
+        });
+
+        let tt = t.clone();
+        spawn(move || tt.run());
+
+        t
+    }
+
+    fn insert(&self, i: Instant) -> Arc<BlackHole> {
+        let b = Arc::new(BlackHole::new());
+
+        self.entries.lock().unwrap().push(Entry(i, b.clone()));
+        self.condvar.notify_one();
+
+        b
+    }
+
+    fn run(&self) {
+        let mut es = self.entries.lock().unwrap();
+
+        loop {
+            let n = Instant::now();
+
+            while es.peek().map(|e| e.0 <= n).unwrap_or(false) {
+                es.pop().unwrap().1.release().unwrap();
+            }
+
+            es = match es.peek().map(|e| e.0) {
+                Some(i) => self.condvar.wait_timeout(es, i - n).unwrap().0,
+                None => self.condvar.wait(es).unwrap(),
+            };
+        }
+    }
+}
+
+struct Entry(Instant, Arc<BlackHole>);
+
+impl Eq for Entry {}
+
+impl Ord for Entry {
+    // Reversed so that the binary heap pops the earliest deadline first.
+    fn cmp(&self, e: &Self) -> Ordering {
+        e.0.cmp(&self.0)
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, e: &Self) -> bool {
+        self.0 == e.0
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, e: &Self) -> Option<Ordering> {
+        Some(self.cmp(e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    #[test]
+    fn wait() {
+        let i = Instant::now();
+        let bs: Vec<_> = vec![200, 0, 100]
+            .into_iter()
+            .map(|n| super::wait(i + Duration::from_millis(n)))
+            .collect();
+
+        block_on_stable(&*bs[1]).unwrap();
+        block_on_stable(&*bs[2]).unwrap();
+        assert!(i.elapsed() >= Duration::from_millis(100));
+
+        block_on_stable(&*bs[0]).unwrap();
+        assert!(i.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -12,12 +12,106 @@ pub fn builtins() -> HashMap<Str, Value> {
         ("+", ADD.clone()),
         ("-", SUBTRACT.clone()),
         ("/", DIVIDE.clone()),
+        ("chars", CHARS.clone()),
+        ("choose", CHOOSE.clone()),
+        ("concat", CONCAT.clone()),
+        ("contains", CONTAINS.clone()),
+        ("cycle", CYCLE.clone()),
+        ("decode", DECODE.clone()),
+        ("delete", DELETE.clone()),
+        ("difference", DIFFERENCE.clone()),
+        ("drop", DROP.clone()),
+        ("encode", ENCODE.clone()),
+        ("endsWith", ENDS_WITH.clone()),
+        ("filter", FILTER.clone()),
         ("first", FIRST.clone()),
+        ("foldl", FOLDL.clone()),
+        ("foldr", FOLDR.clone()),
+        ("format", FORMAT.clone()),
+        ("get", GET.clone()),
         ("if", IF.clone()),
+        ("indexOf", INDEX_OF.clone()),
         ("insert", INSERT.clone()),
+        ("intersection", INTERSECTION.clone()),
+        ("isBoolean", IS_BOOLEAN.clone()),
+        ("isBytes", IS_BYTES.clone()),
+        ("isDictionary", IS_DICTIONARY.clone()),
+        ("isEmpty", IS_EMPTY.clone()),
+        ("isFunction", IS_FUNCTION.clone()),
+        ("isList", IS_LIST.clone()),
+        ("isNil", IS_NIL.clone()),
+        ("isNumber", IS_NUMBER.clone()),
+        ("isSet", IS_SET.clone()),
+        ("isString", IS_STRING.clone()),
+        ("isSubset", IS_SUBSET.clone()),
+        ("isSymbol", IS_SYMBOL.clone()),
+        ("isVector", IS_VECTOR.clone()),
+        ("iterate", ITERATE.clone()),
+        ("join", JOIN.clone()),
+        ("keys", KEYS.clone()),
+        ("length", LENGTH.clone()),
+        ("lower", LOWER.clone()),
+        ("map", MAP.clone()),
         ("merge", MERGE.clone()),
+        ("monotonic", MONOTONIC.clone()),
+        ("now", NOW.clone()),
+        ("parseNumber", PARSE_NUMBER.clone()),
+        ("push", PUSH.clone()),
+        ("random", RANDOM.clone()),
+        ("randomInteger", RANDOM_INTEGER.clone()),
+        ("randomNext", RANDOM_NEXT.clone()),
+        ("randomSplit", RANDOM_SPLIT.clone()),
+        ("range", RANGE.clone()),
+        ("readFile", READ_FILE.clone()),
+        ("regexFind", REGEX_FIND.clone()),
+        ("regexMatch", REGEX_MATCH.clone()),
+        ("regexReplace", REGEX_REPLACE.clone()),
+        ("regexSplit", REGEX_SPLIT.clone()),
+        ("repeat", REPEAT.clone()),
+        ("replace", REPLACE.clone()),
         ("rest", REST.clone()),
+        ("reverse", REVERSE.clone()),
+        ("runCommand", RUN_COMMAND.clone()),
+        ("set", SET.clone()),
+        ("setOf", SET_OF.clone()),
+        ("shuffle", SHUFFLE.clone()),
+        ("size", SIZE.clone()),
+        ("sleep", SLEEP.clone()),
+        ("slice", SLICE.clone()),
+        ("sort", SORT.clone()),
+        ("sortBy", SORT_BY.clone()),
+        ("split", SPLIT.clone()),
+        ("startsWith", STARTS_WITH.clone()),
+        ("take", TAKE.clone()),
+        ("toBoolean", TO_BOOLEAN.clone()),
+        ("toList", TO_LIST.clone()),
+        ("toSet", TO_SET.clone()),
+        ("toString", TO_STRING.clone()),
+        ("toSymbol", TO_SYMBOL.clone()),
+        ("toVector", TO_VECTOR.clone()),
+        ("trim", TRIM.clone()),
+        ("typeOf", TYPE_OF.clone()),
+        ("union", UNION.clone()),
+        ("upper", UPPER.clone()),
+        ("values", VALUES.clone()),
+        ("vector", VECTOR.clone()),
         ("write", WRITE.clone()),
+        ("zip", ZIP.clone()),
+    ] {
+        h.insert(s.into(), v);
+    }
+
+    h
+}
+
+pub fn checked_arithmetic() -> HashMap<Str, Value> {
+    let mut h = HashMap::new();
+
+    for (s, v) in vec![
+        ("*", CHECKED_MULTIPLY.clone()),
+        ("+", CHECKED_ADD.clone()),
+        ("-", CHECKED_SUBTRACT.clone()),
+        ("/", CHECKED_DIVIDE.clone()),
     ] {
         h.insert(s.into(), v);
     }
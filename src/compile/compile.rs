@@ -4,6 +4,6 @@ use super::compiler::Compiler;
 use super::effect::Effect;
 use super::error::CompileError;
 
-pub fn compile(m: Module) -> Result<Vec<Effect>, CompileError> {
-    Compiler::new().compile_module(m)
+pub fn compile(m: Module, checked: bool) -> Result<Vec<Effect>, CompileError> {
+    Compiler::new(checked).compile_module(m)
 }
@@ -2,9 +2,10 @@ use std::collections::HashMap;
 
 use super::super::ast;
 use super::super::builtin::LIST;
+use super::super::core::functions::{MERGE, TO_STRING};
 use super::super::core::{app, Arguments, Dictionary, Expansion, Str, Value};
 
-use super::builtins::builtins;
+use super::builtins::{builtins, checked_arithmetic};
 use super::effect::Effect;
 use super::error::CompileError;
 
@@ -14,13 +15,26 @@ pub struct Compiler {
 }
 
 impl Compiler {
-    pub fn new() -> Self {
-        Compiler {
+    pub fn new(checked: bool) -> Self {
+        let mut c = Compiler {
             environment: builtins(),
+        };
+
+        if checked {
+            c.check_arithmetic();
         }
+
+        c
     }
 
     pub fn compile_module(&mut self, m: ast::Module) -> Result<Vec<Effect>, CompileError> {
+        for p in m.pragmas {
+            match p.name() {
+                "checked" => self.check_arithmetic(),
+                s => return Err(CompileError::new(format!("unknown pragma: {}", s))),
+            }
+        }
+
         // TODO: Use imports field.
 
         let mut es = vec![];
@@ -37,6 +51,10 @@ impl Compiler {
         Ok(es)
     }
 
+    fn check_arithmetic(&mut self) {
+        self.environment.extend(checked_arithmetic());
+    }
+
     fn compile_expression(&mut self, e: ast::Expression) -> Result<Value, CompileError> {
         Ok(match e {
             ast::Expression::App(f, a) => {
@@ -44,6 +62,9 @@ impl Compiler {
             }
             ast::Expression::Number(n) => n.into(),
             ast::Expression::Boolean(b) => b.into(),
+            ast::Expression::Builtin(ast::Builtin::Merge) => MERGE.clone(),
+            ast::Expression::Builtin(ast::Builtin::ToString) => TO_STRING.clone(),
+            ast::Expression::Bytes(bs) => bs.into(),
             ast::Expression::Dictionary(es) => {
                 let mut d: Value = Dictionary::new().into();
 
@@ -58,6 +79,7 @@ impl Compiler {
 
                 d
             }
+            ast::Expression::Interpolation(_) => unreachable!(),
             ast::Expression::List(es) => {
                 let mut ps = vec![];
 
@@ -77,6 +99,7 @@ impl Compiler {
             ast::Expression::Name(n) => self.environment[&n].clone(),
             ast::Expression::Nil => Value::Nil,
             ast::Expression::String(s) => s.into(),
+            ast::Expression::Symbol(s) => s.into(),
         })
     }
 
@@ -8,7 +8,7 @@ use super::dictionary::Dictionary;
 use super::error::Error;
 use super::list::{List, FIRST, REST};
 use super::result::Result;
-use super::string::Str;
+use super::symbol::Symbol;
 use super::unsafe_ref::{Ref, RefMut};
 use super::utils::papp;
 use super::value::Value;
@@ -87,10 +87,10 @@ impl Arguments {
         }
     }
 
-    pub fn search_keyword(mut self: RefMut<Self>, s: Str) -> Result<Value> {
+    pub fn search_keyword(mut self: RefMut<Self>, s: Symbol) -> Result<Value> {
         for k in &mut self.keywords {
             if s == k.name {
-                return Ok(replace(k, KeywordArgument::new("", Value::Nil)).value);
+                return Ok(replace(k, KeywordArgument::new(Symbol::default(), Value::Nil)).value);
             }
         }
 
@@ -99,9 +99,9 @@ impl Arguments {
             .clone()
             .ok_or_else(|| Error::argument("cannot find a keyword argument"))?;
         let d = await!(v.dictionary())?;
-        let v = await!(d.clone().find(s.clone().into()))?;
+        let v = await!(d.clone().find(s.name().into()))?;
 
-        self.expanded_dict = Some(await!(d.delete(s.into()))?.into());
+        self.expanded_dict = Some(await!(d.delete(s.name().into()))?.into());
 
         Ok(v)
     }
@@ -114,7 +114,7 @@ impl Arguments {
 
         for k in &ks {
             let k = k.clone();
-            v = v.insert(k.name, k.value);
+            v = v.insert(k.name.name(), k.value);
         }
 
         v
@@ -171,7 +171,7 @@ impl Arguments {
         for k in ks {
             match k.clone() {
                 Expansion::Expanded(v) => d = d.merge(v),
-                Expansion::Unexpanded(k) => d = d.insert(k.name, k.value),
+                Expansion::Unexpanded(k) => d = d.insert(k.name.name(), k.value),
             }
         }
 
@@ -209,14 +209,14 @@ impl Arguments {
 
         match d {
             Some(ref mut d) => for k in a.keywords.into_iter() {
-                *d = d.insert(k.name.clone(), k.value.clone());
+                *d = d.insert(k.name.name(), k.value.clone());
             },
             None => {
                 let mut dd = Dictionary::new();
 
                 for k in a.keywords.into_iter() {
                     if ks.push_back(k).is_err() {
-                        dd = dd.strict_insert(k.name.clone(), k.value.clone());
+                        dd = dd.strict_insert(k.name.name(), k.value.clone());
                     }
                 }
 
@@ -252,12 +252,12 @@ pub enum Expansion<T> {
 
 #[derive(Clone, Debug)]
 pub struct KeywordArgument {
-    name: Str,
+    name: Symbol,
     value: Value,
 }
 
 impl KeywordArgument {
-    pub fn new(s: impl Into<Str>, v: impl Into<Value>) -> Self {
+    pub fn new(s: impl Into<Symbol>, v: impl Into<Value>) -> Self {
         KeywordArgument {
             name: s.into(),
             value: v.into(),
@@ -0,0 +1,161 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use super::error::Error;
+use super::result::Result;
+use super::signature::Signature;
+use super::string::Str;
+use super::value::Value;
+
+#[derive(Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Bytes(Arc<Vec<u8>>);
+
+impl Bytes {
+    pub fn new(bs: &[u8]) -> Self {
+        Bytes(Arc::new(bs.into()))
+    }
+
+    pub fn merge(bss: &[Self]) -> Self {
+        (bss.iter().map(|bs| bs.as_slice()).collect(): Vec<&[u8]>)
+            .concat()
+            .into()
+    }
+
+    pub fn get(&self, i: usize) -> Option<u8> {
+        self.0.get(i).cloned()
+    }
+
+    pub fn slice(&self, i: usize, j: usize) -> Option<Self> {
+        self.0.get(i..j).map(Self::new)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn to_str(&self) -> Result<Str> {
+        match String::from_utf8(self.as_slice().into()) {
+            Ok(s) => Ok(s.into()),
+            Err(e) => Err(Error::value(&format!(
+                "invalid UTF-8 sequence at byte {}",
+                e.utf8_error().valid_up_to() + 1
+            ))),
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        let mut s = String::from("b\"");
+
+        for &b in self.as_slice() {
+            match b {
+                b'"' => s.push_str("\\\""),
+                b'\\' => s.push_str("\\\\"),
+                b'\n' => s.push_str("\\n"),
+                b'\r' => s.push_str("\\r"),
+                b'\t' => s.push_str("\\t"),
+                b' '...b'~' => s.push(b as char),
+                _ => s.push_str(&format!("\\x{:02x}", b)),
+            }
+        }
+
+        s.push('"');
+        s
+    }
+}
+
+impl Debug for Bytes {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+impl<'a> From<&'a [u8]> for Bytes {
+    fn from(bs: &'a [u8]) -> Self {
+        Bytes::new(bs)
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bs: Vec<u8>) -> Self {
+        Bytes(Arc::new(bs))
+    }
+}
+
+pure_function!(
+    ENCODE,
+    Signature::new(vec!["string".into()], "".into(), vec![], "".into()),
+    encode
+);
+
+async fn encode(vs: Vec<Value>) -> Result<Value> {
+    let s = await!(vs[0].clone().string())?;
+    Ok(Bytes::new(s.as_str().as_bytes()).into())
+}
+
+pure_function!(
+    DECODE,
+    Signature::new(vec!["bytes".into()], "".into(), vec![], "".into()),
+    decode
+);
+
+async fn decode(vs: Vec<Value>) -> Result<Value> {
+    Ok(await!(vs[0].clone().bytes())?.to_str()?.into())
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::utils::papp;
+
+    #[test]
+    fn slice() {
+        let bs = Bytes::new(b"foo");
+
+        assert_eq!(bs.slice(0, 3), Some(bs.clone()));
+        assert_eq!(bs.slice(1, 2), Some(Bytes::new(b"o")));
+        assert_eq!(bs.slice(3, 3), Some(Bytes::default()));
+        assert_eq!(bs.slice(2, 4), None);
+    }
+
+    #[test]
+    fn to_string() {
+        for (bs, s) in vec![
+            (&b""[..], "b\"\""),
+            (&b"foo"[..], "b\"foo\""),
+            (&b"\"\\\n\r\t"[..], "b\"\\\"\\\\\\n\\r\\t\""),
+            (&b"\x00\x7f\xff"[..], "b\"\\x00\\x7f\\xff\""),
+        ] {
+            assert_eq!(Bytes::new(bs).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn encode_and_decode() {
+        for s in vec!["", "foo", "日本語"] {
+            let bs = papp(ENCODE.clone(), &[s.into()]);
+
+            assert_eq!(block_on_stable(bs.clone().bytes()).unwrap().len(), s.len());
+            assert!(block_on_stable(papp(DECODE.clone(), &[bs]).equal(s.into())).unwrap());
+        }
+    }
+
+    #[test]
+    fn decode_error() {
+        let e = block_on_stable(papp(DECODE.clone(), &[Bytes::new(b"a\xff").into()]).pured())
+            .unwrap_err();
+
+        assert_eq!(e.name(), "ValueError");
+        assert_eq!(e.message(), "invalid UTF-8 sequence at byte 2");
+    }
+}
@@ -1,10 +1,14 @@
+use std::convert::TryInto;
+
 use futures::prelude::*;
 
+use super::bytes::Bytes;
 use super::error::Error;
+use super::list::List;
 use super::normal::Normal;
 use super::result::Result;
 use super::signature::Signature;
-use super::string::Str;
+use super::string::{characters, Str};
 use super::value::Value;
 
 pure_function!(
@@ -50,6 +54,16 @@ async fn insert(vs: Vec<Value>) -> Result<Value> {
 
             Value::from(l)
         }
+        Normal::Set(mut s) => {
+            let mut l = await!(vs[1].clone().list())?;
+
+            while !l.is_empty() {
+                s = await!(s.insert(l.first()?))?;
+                l = await!(l.rest())?;
+            }
+
+            Value::from(s)
+        }
         Normal::String(mut s) => {
             let mut l = await!(vs[1].clone().list())?;
 
@@ -60,12 +74,32 @@ async fn insert(vs: Vec<Value>) -> Result<Value> {
                 let m = await!(l.first()?.string())?;
                 l = await!(l.rest())?;
 
-                let (f, l) = s.split(i);
+                let (f, l) = match s.split(i) {
+                    Some(fl) => fl,
+                    None => return Err(Error::index_out_of_range(i as i64 + 1)),
+                };
+
                 s = Str::merge(&[f, m, l]);
             }
 
             Value::from(s)
         }
+        Normal::Vector(mut v) => {
+            let mut l = await!(vs[1].clone().list())?;
+
+            while !l.is_empty() {
+                let i = await!(l.first()?.index())?;
+                l = await!(l.rest())?;
+
+                v = match v.insert(i - 1, l.first()?) {
+                    Some(v) => v,
+                    None => return Err(Error::index_out_of_range(i as i64)),
+                };
+                l = await!(l.rest())?;
+            }
+
+            Value::from(v)
+        }
         n => return Err(await!(Error::not_collection(n.into()))?),
     })
 }
@@ -83,11 +117,32 @@ pure_function!(
 
 async fn merge(vs: Vec<Value>) -> Result<Value> {
     Ok(match await!(vs[0].clone().pured())? {
+        Normal::Bytes(bs) => {
+            let mut l = await!(vs[1].clone().list())?;
+            let mut bss = vec![bs];
+
+            while !l.is_empty() {
+                bss.push(await!(l.first()?.bytes())?);
+                l = await!(l.rest())?;
+            }
+
+            Bytes::merge(&bss).into()
+        }
         Normal::Dictionary(d) => {
             let dd = await!(vs[1].clone().dictionary())?;
             Value::from(d.merge(&dd))
         }
         Normal::List(l) => await!(l.merge(vs[1].clone()))?,
+        Normal::Set(mut s) => {
+            let mut l = await!(vs[1].clone().list())?;
+
+            while !l.is_empty() {
+                s = s.union(&await!(l.first()?.set())?);
+                l = await!(l.rest())?;
+            }
+
+            s.into()
+        }
         Normal::String(mut s) => {
             let mut l = await!(vs[1].clone().list())?;
             let mut ss = vec![s];
@@ -100,6 +155,413 @@ async fn merge(vs: Vec<Value>) -> Result<Value> {
 
             Str::merge(&ss).into()
         }
+        Normal::Vector(mut v) => {
+            let mut l = await!(vs[1].clone().list())?;
+
+            while !l.is_empty() {
+                v = v.merge(&await!(l.first()?.vector())?);
+                l = await!(l.rest())?;
+            }
+
+            v.into()
+        }
+        n => return Err(await!(Error::not_collection(n.into()))?),
+    })
+}
+
+pure_function!(
+    DELETE,
+    Signature::new(vec!["collection".into()], "keys".into(), vec![], "".into()),
+    delete
+);
+
+async fn delete(vs: Vec<Value>) -> Result<Value> {
+    let mut ks = await!(vs[1].clone().list())?;
+
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::Dictionary(mut d) => {
+            while !ks.is_empty() {
+                d = await!(d.delete(ks.first()?))?;
+                ks = await!(ks.rest())?;
+            }
+
+            Value::from(d)
+        }
+        Normal::List(mut l) => {
+            while !ks.is_empty() {
+                let i = await!(integer(ks.first()?))?;
+                ks = await!(ks.rest())?;
+
+                let j = if i > 0 {
+                    i as usize
+                } else {
+                    let n = await!(elements(l.clone(), None))?.len();
+
+                    match position(i, n) {
+                        Some(j) => j + 1,
+                        None => return Err(Error::index_out_of_range(i)),
+                    }
+                };
+
+                l = await!(l.delete(j))?;
+            }
+
+            Value::from(l)
+        }
+        Normal::Set(mut s) => {
+            while !ks.is_empty() {
+                s = await!(s.delete(ks.first()?))?;
+                ks = await!(ks.rest())?;
+            }
+
+            Value::from(s)
+        }
+        Normal::String(s) => {
+            let s: String = s.try_into()?;
+            let mut cs: Vec<char> = s.chars().collect();
+
+            while !ks.is_empty() {
+                let i = await!(integer(ks.first()?))?;
+                ks = await!(ks.rest())?;
+
+                match position(i, cs.len()) {
+                    Some(j) => cs.remove(j),
+                    None => return Err(Error::index_out_of_range(i)),
+                };
+            }
+
+            cs.into_iter().collect::<String>().into()
+        }
+        Normal::Vector(mut v) => {
+            while !ks.is_empty() {
+                let i = await!(integer(ks.first()?))?;
+                ks = await!(ks.rest())?;
+
+                v = match position(i, v.len()).and_then(|j| v.delete(j)) {
+                    Some(v) => v,
+                    None => return Err(Error::index_out_of_range(i)),
+                };
+            }
+
+            Value::from(v)
+        }
+        n => return Err(await!(Error::not_collection(n.into()))?),
+    })
+}
+
+pure_function!(
+    GET,
+    Signature::new(
+        vec!["collection".into(), "index".into()],
+        "default".into(),
+        vec![],
+        "".into()
+    ),
+    get
+);
+
+async fn get(vs: Vec<Value>) -> Result<Value> {
+    let k = vs[1].clone();
+    let d = await!(optional(vs[2].clone(), "get"))?;
+
+    let c = match await!(vs[0].clone().pured())? {
+        Normal::Dictionary(m) => {
+            return match (await!(m.get(k.clone()))?, d) {
+                (Some(v), _) | (None, Some(v)) => Ok(v),
+                (None, None) => Err(await!(Error::key_not_found(k))?),
+            }
+        }
+        n => n,
+    };
+
+    let i = await!(integer(k))?;
+
+    let x = match c {
+        Normal::Bytes(bs) => position(i, bs.len())
+            .and_then(|j| bs.get(j))
+            .map(|b| Value::from(b as usize)),
+        Normal::List(l) => {
+            let vs = await!(elements(l, if i > 0 { Some(i as usize) } else { None }))?;
+            position(i, vs.len()).map(|j| vs[j].clone())
+        }
+        Normal::String(s) => {
+            let s: String = s.try_into()?;
+            let cs: Vec<char> = s.chars().collect();
+            position(i, cs.len()).map(|j| cs[j].to_string().into())
+        }
+        Normal::Vector(v) => position(i, v.len()).and_then(|j| v.get(j)),
+        n => return Err(await!(Error::not_collection(n.into()))?),
+    };
+
+    match (x, d) {
+        (Some(v), _) | (None, Some(v)) => Ok(v),
+        (None, None) => Err(Error::index_out_of_range(i)),
+    }
+}
+
+pure_function!(
+    SLICE,
+    Signature::new(
+        vec!["collection".into(), "start".into()],
+        "end".into(),
+        vec![],
+        "".into()
+    ),
+    slice
+);
+
+async fn slice(vs: Vec<Value>) -> Result<Value> {
+    let s = await!(integer(vs[1].clone()))?;
+    let e = match await!(optional(vs[2].clone(), "slice"))? {
+        Some(e) => Some(await!(integer(e))?),
+        None => None,
+    };
+
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::Bytes(bs) => {
+            let (i, j) = bounds(s, e, bs.len())?;
+            bs.slice(i, j).unwrap().into()
+        }
+        Normal::Dictionary(d) => {
+            let (i, j) = bounds(s, e, d.size())?;
+            d.slice(i, j).into()
+        }
+        Normal::List(l) => {
+            let vs = await!(elements(
+                l,
+                match e {
+                    Some(e) if s > 0 && e >= 0 => Some(e as usize),
+                    _ => None,
+                }
+            ))?;
+
+            let (i, j) = bounds(s, e, vs.len())?;
+            List::new(&vs[i..j]).into()
+        }
+        Normal::String(t) => {
+            let t: String = t.try_into()?;
+            let cs: Vec<char> = t.chars().collect();
+
+            let (i, j) = bounds(s, e, cs.len())?;
+            (&cs[i..j]).iter().collect::<String>().into()
+        }
+        Normal::Vector(v) => {
+            let (i, j) = bounds(s, e, v.len())?;
+            v.slice(i, j).unwrap().into()
+        }
+        n => return Err(await!(Error::not_sequence(n.into()))?),
+    })
+}
+
+fn optional(v: Value, f: &str) -> Result<Option<Value>> {
+    let mut l = await!(v.list())?;
+
+    if l.is_empty() {
+        return Ok(None);
+    }
+
+    let v = l.first()?;
+    l = await!(l.rest())?;
+
+    if !l.is_empty() {
+        return Err(Error::argument(&format!("too many arguments to {}", f)));
+    }
+
+    Ok(Some(v))
+}
+
+fn integer(v: Value) -> Result<i64> {
+    let n = await!(v.number())?;
+
+    if n % 1.0 == 0.0 {
+        Ok(n as i64)
+    } else {
+        Err(Error::value(&format!("{} is not an integer", n)))
+    }
+}
+
+fn position(i: i64, n: usize) -> Option<usize> {
+    let j = if i < 0 { n as i64 + i } else { i - 1 };
+
+    if 0 <= j && j < n as i64 {
+        Some(j as usize)
+    } else {
+        None
+    }
+}
+
+fn bounds(s: i64, e: Option<i64>, n: usize) -> Result<(usize, usize)> {
+    let n = n as i64;
+    let i = if s < 0 { n + s } else { s - 1 };
+
+    if i < 0 || i > n {
+        return Err(Error::index_out_of_range(s));
+    }
+
+    let j = match e {
+        Some(e) if e < 0 => n + e + 1,
+        Some(e) => e,
+        None => n,
+    };
+
+    if j < i || j > n {
+        return Err(Error::index_out_of_range(e.unwrap_or(n)));
+    }
+
+    Ok((i as usize, j as usize))
+}
+
+fn any_equal(vs: Vec<Value>, x: Value) -> Result<bool> {
+    for v in vs {
+        if await!(v.equal(x.clone()))? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+fn elements(mut l: List, n: Option<usize>) -> Result<Vec<Value>> {
+    let mut vs = vec![];
+
+    while !l.is_empty() && n.map(|n| vs.len() < n).unwrap_or(true) {
+        vs.push(l.first()?);
+        l = await!(l.rest())?;
+    }
+
+    Ok(vs)
+}
+
+pure_function!(
+    SIZE,
+    Signature::new(vec!["collection".into()], "".into(), vec![], "".into()),
+    size
+);
+
+async fn size(vs: Vec<Value>) -> Result<Value> {
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::Bytes(bs) => bs.len(),
+        Normal::Dictionary(d) => d.size(),
+        Normal::List(l) => await!(elements(l, None))?.len(),
+        Normal::Set(s) => s.size(),
+        Normal::String(s) => {
+            let s: String = s.try_into()?;
+            s.chars().count()
+        }
+        Normal::Vector(v) => v.len(),
+        n => return Err(await!(Error::not_collection(n.into()))?),
+    }.into())
+}
+
+pure_function!(
+    IS_EMPTY,
+    Signature::new(vec!["collection".into()], "".into(), vec![], "".into()),
+    is_empty
+);
+
+async fn is_empty(vs: Vec<Value>) -> Result<Value> {
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::Bytes(bs) => bs.is_empty(),
+        Normal::Dictionary(d) => d.size() == 0,
+        Normal::List(l) => l.is_empty(),
+        Normal::Set(s) => s.size() == 0,
+        Normal::String(s) => s == "",
+        Normal::Vector(v) => v.is_empty(),
+        n => return Err(await!(Error::not_collection(n.into()))?),
+    }.into())
+}
+
+pure_function!(
+    TO_LIST,
+    Signature::new(vec!["collection".into()], "".into(), vec![], "".into()),
+    to_list
+);
+
+async fn to_list(vs: Vec<Value>) -> Result<Value> {
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::Bytes(bs) => List::new(
+            &(bs.as_slice()
+                .iter()
+                .map(|&b| Value::from(b as usize))
+                .collect(): Vec<Value>),
+        ).into(),
+        Normal::Dictionary(d) => List::new(
+            &(d.to_vec()
+                .into_iter()
+                .map(|(k, v)| List::new(&[k, v]).into())
+                .collect(): Vec<Value>),
+        ).into(),
+        Normal::List(l) => l.into(),
+        Normal::Set(s) => List::new(&s.to_vec()).into(),
+        Normal::String(s) => List::new(&characters(s)?).into(),
+        Normal::Vector(v) => v.to_list().into(),
+        n => return Err(await!(Error::not_collection(n.into()))?),
+    })
+}
+
+pure_function!(
+    CONTAINS,
+    Signature::new(
+        vec!["collection".into(), "element".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    contains
+);
+
+async fn contains(vs: Vec<Value>) -> Result<Value> {
+    let x = vs[1].clone();
+
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::Dictionary(d) => await!(d.get(x))?.is_some(),
+        Normal::List(l) => await!(any_equal(await!(elements(l, None))?, x))?,
+        Normal::Set(s) => await!(s.contains(x))?,
+        Normal::String(s) => {
+            let s: String = s.try_into()?;
+            let t: String = await!(x.string())?.try_into()?;
+            s.contains(&t)
+        }
+        Normal::Vector(v) => await!(any_equal(v.to_vec(), x))?,
+        n => return Err(await!(Error::not_collection(n.into()))?),
+    }.into())
+}
+
+pure_function!(
+    KEYS,
+    Signature::new(vec!["collection".into()], "".into(), vec![], "".into()),
+    keys
+);
+
+async fn keys(vs: Vec<Value>) -> Result<Value> {
+    let n = match await!(vs[0].clone().pured())? {
+        Normal::Dictionary(d) => {
+            let ks: Vec<Value> = d.to_vec().into_iter().map(|(k, _)| k).collect();
+            return Ok(List::new(&ks).into());
+        }
+        Normal::List(l) => await!(elements(l, None))?.len(),
+        Normal::String(s) => s.len(),
+        Normal::Vector(v) => v.len(),
+        n => return Err(await!(Error::not_collection(n.into()))?),
+    };
+
+    Ok(List::new(&((1..n + 1).map(Value::from).collect(): Vec<Value>)).into())
+}
+
+pure_function!(
+    VALUES,
+    Signature::new(vec!["collection".into()], "".into(), vec![], "".into()),
+    values
+);
+
+async fn values(vs: Vec<Value>) -> Result<Value> {
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::Dictionary(d) => {
+            List::new(&(d.to_vec().into_iter().map(|(_, v)| v).collect(): Vec<Value>)).into()
+        }
+        Normal::List(l) => l.into(),
+        Normal::String(s) => List::new(&characters(s)?).into(),
+        Normal::Vector(v) => v.to_list().into(),
         n => return Err(await!(Error::not_collection(n.into()))?),
     })
 }
@@ -110,8 +572,11 @@ mod test {
 
     use super::*;
 
-    use super::super::list::List;
+    use super::super::dictionary::Dictionary;
+    use super::super::sequence::REPEAT;
+    use super::super::set::Set;
     use super::super::utils::papp;
+    use super::super::vector::Vector;
 
     #[test]
     fn merge() {
@@ -142,9 +607,384 @@ mod test {
                 &["foo".into(), "bar".into(), "baz".into()],
                 "foobarbaz".into(),
             ),
+            (
+                &[
+                    Vector::new(&[0.into()]).into(),
+                    Vector::default().into(),
+                    Vector::new(&[1.into(), 2.into()]).into(),
+                ],
+                Vector::new(&[0.into(), 1.into(), 2.into()]).into(),
+            ),
+            (
+                &[Bytes::new(b"foo").into(), Bytes::new(b"\xff").into()],
+                Bytes::new(b"foo\xff").into(),
+            ),
         ]: Vec<(&[Value], Value)>
         {
             assert!(block_on_stable(papp(MERGE.clone(), vs).equal(x)).unwrap());
         }
     }
+
+    fn vector(n: usize) -> Value {
+        Vector::new(&((0..n).map(Value::from).collect(): Vec<Value>)).into()
+    }
+
+    #[test]
+    fn insert_vector() {
+        for (vs, x) in vec![
+            (
+                vec![vector(2), 1.into(), 42.into()],
+                Vector::new(&[42.into(), 0.into(), 1.into()]),
+            ),
+            (
+                vec![vector(2), 3.into(), 42.into()],
+                Vector::new(&[0.into(), 1.into(), 42.into()]),
+            ),
+            (
+                vec![vector(2), 2.into(), 42.into(), 1.into(), 43.into()],
+                Vector::new(&[43.into(), 0.into(), 42.into(), 1.into()]),
+            ),
+        ]: Vec<(Vec<Value>, Vector)>
+        {
+            assert!(block_on_stable(papp(INSERT.clone(), &vs).equal(x.into())).unwrap());
+        }
+
+        assert!(
+            block_on_stable(papp(INSERT.clone(), &[vector(2), 4.into(), 42.into()]).pured())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn insert_string() {
+        for (vs, x) in vec![
+            (vec!["foo".into(), 1.into(), "bar".into()], "barfoo"),
+            (vec!["foo".into(), 4.into(), "bar".into()], "foobar"),
+            (vec!["héllo".into(), 3.into(), "ö".into()], "héöllo"),
+            (vec!["日本".into(), 2.into(), "語".into()], "日語本"),
+            (vec!["日本".into(), 3.into(), "語".into()], "日本語"),
+            (vec!["👍".into(), 1.into(), "a".into(), 3.into(), "b".into()], "a👍b"),
+        ]: Vec<(Vec<Value>, &str)>
+        {
+            assert!(block_on_stable(papp(INSERT.clone(), &vs).equal(x.into())).unwrap());
+        }
+
+        assert_eq!(
+            block_on_stable(papp(INSERT.clone(), &["héllo".into(), 7.into(), "!".into()]).pured())
+                .unwrap_err()
+                .name(),
+            "ValueError"
+        );
+    }
+
+    fn list(n: usize) -> Value {
+        List::new(&((0..n).map(Value::from).collect(): Vec<Value>)).into()
+    }
+
+    #[test]
+    fn get() {
+        let d: Value = Dictionary::new().strict_insert("foo", 42).into();
+
+        for (vs, x) in vec![
+            (vec![vector(3), 1.into()], 0.into()),
+            (vec![vector(3), 3.into()], 2.into()),
+            (vec![vector(3), (-1.0).into()], 2.into()),
+            (vec![list(3), 2.into()], 1.into()),
+            (vec![list(3), (-3.0).into()], 0.into()),
+            (vec![papp(REPEAT.clone(), &[42.into()]), 100.into()], 42.into()),
+            (vec!["foo".into(), 1.into()], "f".into()),
+            (vec!["héllo".into(), 2.into()], "é".into()),
+            (vec!["foo".into(), (-1.0).into()], "o".into()),
+            (vec![Bytes::new(b"\x00\xff").into(), 2.into()], 255.into()),
+            (vec![d.clone(), "foo".into()], 42.into()),
+            (vec![list(3), 4.into(), 42.into()], 42.into()),
+            (vec![vector(3), 0.into(), 42.into()], 42.into()),
+            (vec!["".into(), 1.into(), Value::Nil], Value::Nil),
+            (vec![d.clone(), "bar".into(), Value::Nil], Value::Nil),
+        ]: Vec<(Vec<Value>, Value)>
+        {
+            assert!(block_on_stable(papp(GET.clone(), &vs).equal(x)).unwrap());
+        }
+
+        for (vs, e) in vec![
+            (vec![vector(3), 0.into()], "ValueError"),
+            (vec![vector(3), 4.into()], "ValueError"),
+            (vec![list(3), (-4.0).into()], "ValueError"),
+            (vec![list(3), 1.5.into()], "ValueError"),
+            (vec!["".into(), 1.into()], "ValueError"),
+            (vec![d.clone(), "bar".into()], "KeyNotFoundError"),
+            (vec![42.into(), 1.into()], "TypeError"),
+            (vec![list(3), 4.into(), 1.into(), 2.into()], "ArgumentError"),
+        ]: Vec<(Vec<Value>, &str)>
+        {
+            assert_eq!(block_on_stable(papp(GET.clone(), &vs).pured()).unwrap_err().name(), e);
+        }
+    }
+
+    #[test]
+    fn slice() {
+        for (vs, x) in vec![
+            (vec![vector(3), 1.into()], vec![0, 1, 2]),
+            (vec![vector(3), 2.into()], vec![1, 2]),
+            (vec![vector(3), 1.into(), 2.into()], vec![0, 1]),
+            (vec![vector(3), 2.into(), 2.into()], vec![1]),
+            (vec![vector(3), 2.into(), 1.into()], vec![]),
+            (vec![vector(3), 4.into()], vec![]),
+            (vec![vector(3), (-1.0).into(), (-1.0).into()], vec![2]),
+            (vec![vector(3), 1.into(), (-4.0).into()], vec![]),
+        ]: Vec<(Vec<Value>, Vec<usize>)>
+        {
+            let x = Vector::new(&(x.into_iter().map(Value::from).collect(): Vec<Value>));
+            assert!(block_on_stable(papp(SLICE.clone(), &vs).equal(x.into())).unwrap());
+        }
+
+        for (vs, x) in vec![
+            (vec![list(5), 2.into(), (-2.0).into()], vec![1, 2, 3]),
+            (vec![list(3), (-2.0).into()], vec![1, 2]),
+            (vec![list(3), 1.into(), 0.into()], vec![]),
+            (vec![papp(REPEAT.clone(), &[0.into()]), 1.into(), 2.into()], vec![0, 0]),
+        ]: Vec<(Vec<Value>, Vec<usize>)>
+        {
+            let x = List::new(&(x.into_iter().map(Value::from).collect(): Vec<Value>));
+            assert!(block_on_stable(papp(SLICE.clone(), &vs).equal(x.into())).unwrap());
+        }
+
+        for (vs, x) in vec![
+            (vec!["hello".into(), 2.into(), 4.into()], "ell"),
+            (vec!["héllo".into(), (-3.0).into()], "llo"),
+            (vec!["héllo".into(), 2.into(), 2.into()], "é"),
+            (vec!["".into(), 1.into()], ""),
+        ]: Vec<(Vec<Value>, &str)>
+        {
+            assert!(block_on_stable(papp(SLICE.clone(), &vs).equal(x.into())).unwrap());
+        }
+
+        let d: Value = Dictionary::new()
+            .strict_insert("a", 1)
+            .strict_insert("b", 2)
+            .strict_insert("c", 3)
+            .into();
+
+        for (vs, x) in vec![
+            (vec![d.clone(), 2.into()], vec![("b", 2), ("c", 3)]),
+            (vec![d.clone(), 1.into(), 2.into()], vec![("a", 1), ("b", 2)]),
+            (vec![d.clone(), (-1.0).into()], vec![("c", 3)]),
+            (vec![d.clone(), 4.into()], vec![]),
+        ]: Vec<(Vec<Value>, Vec<(&str, usize)>)>
+        {
+            let mut y = Dictionary::new();
+
+            for (k, v) in x {
+                y = y.strict_insert(k, v);
+            }
+
+            assert!(block_on_stable(papp(SLICE.clone(), &vs).equal(y.into())).unwrap());
+        }
+
+        assert!(
+            block_on_stable(
+                papp(SLICE.clone(), &[Bytes::new(b"foo").into(), 2.into()])
+                    .equal(Bytes::new(b"oo").into())
+            ).unwrap()
+        );
+
+        for vs in vec![
+            vec![vector(3), 0.into()],
+            vec![vector(3), 5.into()],
+            vec![vector(3), 1.into(), 4.into()],
+            vec![vector(3), 3.into(), 1.into()],
+            vec![vector(3), 1.into(), 2.into(), 3.into()],
+            vec![list(3), 1.into(), 4.into()],
+            vec![list(3), (-4.0).into()],
+            vec!["foo".into(), 2.into(), 4.into()],
+            vec![Dictionary::new().into(), 2.into()],
+            vec![42.into(), 1.into()],
+        ]: Vec<Vec<Value>>
+        {
+            assert!(block_on_stable(papp(SLICE.clone(), &vs).pured()).is_err());
+        }
+    }
+
+    #[test]
+    fn size() {
+        for (v, n) in vec![
+            (Dictionary::new().into(), 0),
+            (Dictionary::new().strict_insert("foo", 42).into(), 1),
+            (list(0), 0),
+            (list(3), 3),
+            ("".into(), 0),
+            ("héllo".into(), 5),
+            (Bytes::new("héllo".as_bytes()).into(), 6),
+            (vector(3), 3),
+        ]: Vec<(Value, usize)>
+        {
+            assert_eq!(block_on_stable(papp(SIZE.clone(), &[v]).number()).unwrap(), n as f64);
+        }
+
+        assert!(block_on_stable(papp(SIZE.clone(), &[42.into()]).pured()).is_err());
+    }
+
+    #[test]
+    fn is_empty() {
+        for (v, b) in vec![
+            (Dictionary::new().into(), true),
+            (Dictionary::new().strict_insert("foo", 42).into(), false),
+            (list(0), true),
+            (papp(REPEAT.clone(), &[42.into()]), false),
+            ("".into(), true),
+            ("foo".into(), false),
+            (Bytes::default().into(), true),
+            (vector(0), true),
+            (vector(1), false),
+        ]: Vec<(Value, bool)>
+        {
+            assert_eq!(block_on_stable(papp(IS_EMPTY.clone(), &[v]).boolean()).unwrap(), b);
+        }
+
+        assert!(block_on_stable(papp(IS_EMPTY.clone(), &[Value::Nil]).pured()).is_err());
+    }
+
+    #[test]
+    fn to_list() {
+        for (v, x) in vec![
+            (list(2), list(2)),
+            (vector(2), list(2)),
+            ("".into(), List::Empty.into()),
+            ("hé".into(), List::new(&["h".into(), "é".into()]).into()),
+            (Bytes::new(b"\x00\xff").into(), List::new(&[0.into(), 255.into()]).into()),
+            (Dictionary::new().into(), List::Empty.into()),
+            (
+                Dictionary::new().strict_insert("foo", 42).into(),
+                List::new(&[List::new(&["foo".into(), 42.into()]).into()]).into(),
+            ),
+        ]: Vec<(Value, Value)>
+        {
+            assert!(block_on_stable(papp(TO_LIST.clone(), &[v]).equal(x)).unwrap());
+        }
+
+        assert!(block_on_stable(papp(TO_LIST.clone(), &[42.into()]).pured()).is_err());
+    }
+
+    #[test]
+    fn contains() {
+        let d: Value = Dictionary::new().strict_insert("foo", 42).into();
+
+        for (c, x, b) in vec![
+            (d.clone(), "foo".into(), true),
+            (d.clone(), 42.into(), false),
+            (list(3), 2.into(), true),
+            (list(3), 3.into(), false),
+            (list(3), "foo".into(), false),
+            (vector(3), 0.into(), true),
+            (vector(3), 3.into(), false),
+            ("héllo".into(), "éll".into(), true),
+            ("hello".into(), "".into(), true),
+            ("hello".into(), "world".into(), false),
+        ]: Vec<(Value, Value, bool)>
+        {
+            assert_eq!(block_on_stable(papp(CONTAINS.clone(), &[c, x]).boolean()).unwrap(), b);
+        }
+
+        for (c, x) in vec![("foo".into(), 42.into()), (42.into(), 42.into())]: Vec<(Value, Value)> {
+            assert!(block_on_stable(papp(CONTAINS.clone(), &[c, x]).pured()).is_err());
+        }
+    }
+
+    #[test]
+    fn keys_and_values() {
+        let d: Value = Dictionary::new().strict_insert("foo", 42).into();
+
+        for (f, v, x) in vec![
+            (KEYS.clone(), d.clone(), List::new(&["foo".into()]).into()),
+            (VALUES.clone(), d.clone(), List::new(&[42.into()]).into()),
+            (KEYS.clone(), vector(2), List::new(&[1.into(), 2.into()]).into()),
+            (VALUES.clone(), vector(2), list(2)),
+            (KEYS.clone(), list(2), List::new(&[1.into(), 2.into()]).into()),
+            (VALUES.clone(), list(2), list(2)),
+            (KEYS.clone(), "hé".into(), List::new(&[1.into(), 2.into()]).into()),
+            (VALUES.clone(), "hé".into(), List::new(&["h".into(), "é".into()]).into()),
+        ]: Vec<(Value, Value, Value)>
+        {
+            assert!(block_on_stable(papp(f, &[v]).equal(x)).unwrap());
+        }
+
+        for f in vec![KEYS.clone(), VALUES.clone()] {
+            assert!(block_on_stable(papp(f, &[42.into()]).pured()).is_err());
+        }
+    }
+
+    #[test]
+    fn delete() {
+        let d: Value = Dictionary::new()
+            .strict_insert("foo", 1)
+            .strict_insert("bar", 2)
+            .into();
+
+        for (vs, x) in vec![
+            (
+                vec![d.clone(), "foo".into()],
+                Dictionary::new().strict_insert("bar", 2).into(),
+            ),
+            (
+                vec![d.clone(), "foo".into(), "bar".into()],
+                Dictionary::new().into(),
+            ),
+            (vec![list(3), 1.into()], List::new(&[1.into(), 2.into()]).into()),
+            (vec![list(3), (-1.0).into()], List::new(&[0.into(), 1.into()]).into()),
+            (vec![list(3), 1.into(), 1.into()], List::new(&[2.into()]).into()),
+            (vec!["héllo".into(), 2.into()], "hllo".into()),
+            (vec!["hello".into(), (-1.0).into(), 1.into()], "ell".into()),
+            (vec![vector(3), 2.into()], Vector::new(&[0.into(), 2.into()]).into()),
+            (vec![vector(3)], vector(3)),
+        ]: Vec<(Vec<Value>, Value)>
+        {
+            assert!(block_on_stable(papp(DELETE.clone(), &vs).equal(x)).unwrap());
+        }
+
+        for (vs, e) in vec![
+            (vec![d.clone(), "baz".into()], "KeyNotFoundError"),
+            (vec![list(3), 4.into()], "ValueError"),
+            (vec![list(3), (-4.0).into()], "ValueError"),
+            (vec!["foo".into(), 0.into()], "ValueError"),
+            (vec![vector(3), 4.into()], "ValueError"),
+            (vec![42.into(), 1.into()], "TypeError"),
+        ]: Vec<(Vec<Value>, &str)>
+        {
+            assert_eq!(block_on_stable(papp(DELETE.clone(), &vs).pured()).unwrap_err().name(), e);
+        }
+    }
+
+    #[test]
+    fn set() {
+        let s: Value = Set::new().strict_insert(1.0).into();
+
+        for (f, vs, x) in vec![
+            (
+                INSERT.clone(),
+                vec![s.clone(), 2.into(), 1.into()],
+                Set::new().strict_insert(1.0).strict_insert(2.0),
+            ),
+            (
+                MERGE.clone(),
+                vec![s.clone(), Set::new().strict_insert(2.0).into()],
+                Set::new().strict_insert(1.0).strict_insert(2.0),
+            ),
+            (DELETE.clone(), vec![s.clone(), 1.into()], Set::new()),
+        ]: Vec<(Value, Vec<Value>, Set)>
+        {
+            assert!(block_on_stable(papp(f, &vs).equal(x.into())).unwrap());
+        }
+
+        for (f, x) in vec![
+            (SIZE.clone(), 1.into()),
+            (IS_EMPTY.clone(), false.into()),
+            (TO_LIST.clone(), List::new(&[1.into()]).into()),
+        ]: Vec<(Value, Value)>
+        {
+            assert!(block_on_stable(papp(f, &[s.clone()]).equal(x)).unwrap());
+        }
+
+        assert!(block_on_stable(papp(CONTAINS.clone(), &[s.clone(), 1.into()]).boolean()).unwrap());
+        assert!(!block_on_stable(papp(CONTAINS.clone(), &[s, 2.into()]).boolean()).unwrap());
+    }
 }
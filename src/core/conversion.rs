@@ -0,0 +1,173 @@
+use std::convert::TryInto;
+use std::str::FromStr;
+
+use super::error::Error;
+use super::normal::Normal;
+use super::result::Result;
+use super::signature::Signature;
+use super::symbol::{is_name, Symbol};
+use super::value::Value;
+
+pure_function!(
+    TO_STRING,
+    Signature::new(vec!["x".into()], "".into(), vec![], "".into()),
+    to_string
+);
+
+async fn to_string(vs: Vec<Value>) -> Result<Value> {
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::String(s) => s.into(),
+        Normal::Symbol(s) => s.name().into(),
+        n => await!(n.to_string())?.into(),
+    })
+}
+
+pure_function!(
+    TO_SYMBOL,
+    Signature::new(vec!["x".into()], "".into(), vec![], "".into()),
+    to_symbol
+);
+
+async fn to_symbol(vs: Vec<Value>) -> Result<Value> {
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::String(s) => if !is_name(s.as_str()) {
+            return Err(Error::value(&format!("{:?} is not a valid symbol name", s)));
+        } else if let Some(s) = Symbol::try_new(s) {
+            s.into()
+        } else {
+            return Err(Error::value("too many symbols"));
+        },
+        Normal::Symbol(s) => s.into(),
+        n => return Err(await!(Error::not_string(n.into()))?),
+    })
+}
+
+pure_function!(
+    PARSE_NUMBER,
+    Signature::new(vec!["string".into()], "".into(), vec![], "".into()),
+    parse_number
+);
+
+async fn parse_number(vs: Vec<Value>) -> Result<Value> {
+    let s: String = await!(vs[0].clone().string())?.try_into()?;
+
+    match f64::from_str(s.trim()) {
+        Ok(n) if n.is_finite() => Ok(n.into()),
+        _ => Err(Error::value(&format!("{:?} is not a number", s))),
+    }
+}
+
+pure_function!(
+    TO_BOOLEAN,
+    Signature::new(vec!["x".into()], "".into(), vec![], "".into()),
+    to_boolean
+);
+
+async fn to_boolean(vs: Vec<Value>) -> Result<Value> {
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::Boolean(b) => b.into(),
+        Normal::Number(n) => (n != 0.0).into(),
+        Normal::String(s) => if s == "true" {
+            true.into()
+        } else if s == "false" {
+            false.into()
+        } else {
+            return Err(Error::value(&format!("{:?} is not a boolean", s)));
+        },
+        n => return Err(await!(Error::not_boolean(n.into()))?),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::list::List;
+    use super::super::utils::papp;
+
+    #[test]
+    fn to_string() {
+        for (x, s) in vec![
+            (true.into(), "true"),
+            (42.into(), "42"),
+            (1.5.into(), "1.5"),
+            (Value::Nil, "nil"),
+            ("foo".into(), "foo"),
+            (List::new(&["foo".into()]).into(), "[\"foo\"]"),
+            (Symbol::from("foo").into(), "foo"),
+        ]: Vec<(Value, &str)>
+        {
+            assert!(block_on_stable(papp(TO_STRING.clone(), &[x]).equal(s.into())).unwrap());
+        }
+    }
+
+    #[test]
+    fn to_symbol() {
+        for x in vec!["foo".into(), Symbol::from("foo").into()]: Vec<Value> {
+            assert!(
+                block_on_stable(
+                    papp(TO_SYMBOL.clone(), &[x]).equal(Symbol::from("foo").into())
+                ).unwrap()
+            );
+        }
+
+        for (x, e) in vec![
+            ("".into(), "ValueError"),
+            ("foo bar".into(), "ValueError"),
+            (":foo".into(), "ValueError"),
+            (".foo".into(), "ValueError"),
+            (42.into(), "TypeError"),
+        ]: Vec<(Value, &str)>
+        {
+            assert_eq!(
+                block_on_stable(papp(TO_SYMBOL.clone(), &[x]).pured()).unwrap_err().name(),
+                e
+            );
+        }
+    }
+
+    #[test]
+    fn parse_number() {
+        for (s, n) in vec![("42", 42.0), ("-1.5", -1.5), (" 0 ", 0.0), ("1e3", 1000.0)] {
+            assert_eq!(
+                block_on_stable(papp(PARSE_NUMBER.clone(), &[s.into()]).number()).unwrap(),
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn parse_number_error() {
+        for s in vec!["", "foo", "4 2", "inf", "NaN"] {
+            let e = block_on_stable(papp(PARSE_NUMBER.clone(), &[s.into()]).number()).unwrap_err();
+            assert_eq!(e.name(), "ValueError");
+        }
+    }
+
+    #[test]
+    fn to_boolean() {
+        for (x, b) in vec![
+            (true.into(), true),
+            (false.into(), false),
+            (1.into(), true),
+            (0.into(), false),
+            ("true".into(), true),
+            ("false".into(), false),
+        ]: Vec<(Value, bool)>
+        {
+            assert_eq!(
+                block_on_stable(papp(TO_BOOLEAN.clone(), &[x]).boolean()).unwrap(),
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn to_boolean_error() {
+        for x in vec!["foo".into(), Value::Nil, List::Empty.into()]: Vec<Value> {
+            assert!(block_on_stable(papp(TO_BOOLEAN.clone(), &[x]).boolean()).is_err());
+        }
+    }
+}
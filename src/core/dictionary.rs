@@ -1,47 +1,139 @@
-use std::convert::{TryFrom, TryInto};
+use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
-use std::mem::transmute;
 use std::sync::Arc;
 
 use futures::prelude::*;
 use hamt_sync::Map;
 
 use super::error::Error;
+use super::list::List;
 use super::normal::Normal;
 use super::result::Result;
 use super::string::Str;
+use super::symbol::Symbol;
 use super::value::Value;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub enum Key {
+    Boolean(bool),
+    Dictionary(Arc<Vec<(Key, Key)>>),
+    List(Arc<Vec<Key>>),
     Nil,
     Number(f64),
     String(Str),
+    Symbol(Symbol),
+}
+
+impl Key {
+    pub fn from_value(v: Value) -> Result<Self> {
+        Ok(match await!(v.pured())? {
+            Normal::Boolean(b) => Key::Boolean(b),
+            Normal::Dictionary(d) => {
+                let mut kvs = vec![];
+
+                for (k, v) in d.entries() {
+                    kvs.push((k, await!(Self::from_value(v))?));
+                }
+
+                kvs.sort_by(|x, y| x.0.cmp(&y.0));
+
+                Key::Dictionary(Arc::new(kvs))
+            }
+            Normal::List(l) => {
+                let mut ks = vec![];
+
+                for v in await!(l.to_vec())? {
+                    ks.push(await!(Self::from_value(v))?);
+                }
+
+                Key::List(Arc::new(ks))
+            }
+            Normal::Nil => Key::Nil,
+            Normal::Number(n) => if n.is_nan() {
+                return Err(Error::value("NaN cannot be a key in dictionaries"));
+            } else {
+                n.into()
+            },
+            Normal::String(s) => Key::String(s),
+            Normal::Symbol(s) => Key::Symbol(s),
+            n => {
+                return Err(Error::typ_raw(&format!(
+                    "{} cannot be a key in dictionaries",
+                    await!(n.to_string())?
+                )))
+            }
+        })
+    }
+
+    fn rank(&self) -> u8 {
+        match *self {
+            Key::Boolean(_) => 0,
+            Key::Dictionary(_) => 1,
+            Key::List(_) => 2,
+            Key::Nil => 3,
+            Key::Number(_) => 4,
+            Key::String(_) => 5,
+            Key::Symbol(_) => 6,
+        }
+    }
 }
 
+impl Eq for Key {}
+
 impl Hash for Key {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(self.rank());
+
         match *self {
-            Key::Nil => state.write_u8(0),
-            Key::Number(n) => state.write_u64(unsafe { transmute(n) }),
-            Key::String(ref s) => state.write(s.into()),
+            Key::Boolean(b) => b.hash(state),
+            Key::Dictionary(ref kvs) => kvs.hash(state),
+            Key::List(ref ks) => ks.hash(state),
+            Key::Nil => {}
+            Key::Number(n) => state.write_u64(n.to_bits()),
+            Key::String(ref s) => s.hash(state),
+            Key::Symbol(s) => s.hash(state),
         }
     }
 }
 
-impl TryFrom<Normal> for Key {
-    type Error = Error;
-
-    fn try_from(n: Normal) -> Result<Self> {
-        match n {
-            Normal::Nil => Ok(Key::Nil),
-            Normal::Number(n) => Ok(Key::Number(n)),
-            Normal::String(s) => Ok(Key::String(s)),
-            _ => Err(Error::value("{} cannot be a key in dictionaries")),
+impl Ord for Key {
+    fn cmp(&self, k: &Self) -> Ordering {
+        match (self, k) {
+            (&Key::Boolean(x), &Key::Boolean(y)) => x.cmp(&y),
+            (&Key::Dictionary(ref x), &Key::Dictionary(ref y)) => x.cmp(y),
+            (&Key::List(ref x), &Key::List(ref y)) => x.cmp(y),
+            (&Key::Number(x), &Key::Number(y)) => total(x).cmp(&total(y)),
+            (&Key::String(ref x), &Key::String(ref y)) => x.cmp(y),
+            (&Key::Symbol(x), &Key::Symbol(y)) => x.cmp(&y),
+            _ => self.rank().cmp(&k.rank()),
         }
     }
 }
 
+impl PartialEq for Key {
+    fn eq(&self, k: &Self) -> bool {
+        self.cmp(k) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, k: &Self) -> Option<Ordering> {
+        Some(self.cmp(k))
+    }
+}
+
+// Orders numbers totally including NaNs consistently with hashes of their bits.
+fn total(n: f64) -> i64 {
+    let b = n.to_bits() as i64;
+    b ^ (((b >> 63) as u64) >> 1) as i64
+}
+
+impl From<f64> for Key {
+    fn from(n: f64) -> Self {
+        Key::Number(if n == 0.0 { 0.0 } else { n })
+    }
+}
+
 impl<'a> From<&'a str> for Key {
     fn from(s: &'a str) -> Self {
         Key::String(s.into())
@@ -54,12 +146,41 @@ impl From<Str> for Key {
     }
 }
 
+impl From<Symbol> for Key {
+    fn from(s: Symbol) -> Self {
+        Key::Symbol(s)
+    }
+}
+
 impl Into<Normal> for Key {
     fn into(self) -> Normal {
         match self {
+            Key::Boolean(b) => Normal::Boolean(b),
+            Key::Dictionary(kvs) => {
+                let mut d = Dictionary::new();
+
+                for (k, v) in kvs.iter() {
+                    let v: Normal = v.clone().into();
+                    d = d.strict_insert(k.clone(), v);
+                }
+
+                Normal::Dictionary(d)
+            }
+            Key::List(ks) => {
+                let vs: Vec<Value> = ks
+                    .iter()
+                    .map(|k| {
+                        let n: Normal = k.clone().into();
+                        n.into()
+                    })
+                    .collect();
+
+                Normal::List(List::new(&vs))
+            }
             Key::Nil => Normal::Nil,
             Key::Number(n) => Normal::Number(n),
             Key::String(s) => Normal::String(s),
+            Key::Symbol(s) => Normal::Symbol(s),
         }
     }
 }
@@ -75,13 +196,7 @@ impl Dictionary {
     pub fn to_string(self) -> Result<String> {
         let mut ss = vec![];
 
-        let kvs: Vec<(Key, Value)> = self
-            .0
-            .into_iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect();
-
-        for (k, v) in kvs {
+        for (k, v) in self.entries() {
             let n: Normal = k.into();
             let k = await!(n.to_string())?;
             ss.push(k);
@@ -97,8 +212,39 @@ impl Dictionary {
         self.0.size()
     }
 
+    pub fn slice(&self, i: usize, j: usize) -> Self {
+        let mut d = Dictionary::new();
+
+        for (k, v) in self.entries()[i..j].iter().cloned() {
+            d = d.strict_insert(k, v);
+        }
+
+        d
+    }
+
+    fn entries(&self) -> Vec<(Key, Value)> {
+        let mut kvs: Vec<(Key, Value)> = self
+            .0
+            .into_iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        kvs.sort_by(|x, y| x.0.cmp(&y.0));
+        kvs
+    }
+
+    pub fn to_vec(&self) -> Vec<(Value, Value)> {
+        self.entries()
+            .into_iter()
+            .map(|(k, v)| {
+                let n: Normal = k.into();
+                (n.into(), v)
+            })
+            .collect()
+    }
+
     pub fn insert(self, k: Value, v: Value) -> Result<Dictionary> {
-        let k = Key::try_from(await!(k.pured())?)?;
+        let k = await!(Key::from_value(k))?;
         Ok(Dictionary::from(self.0.insert(k, v)))
     }
 
@@ -116,9 +262,13 @@ impl Dictionary {
         Dictionary::from(m)
     }
 
+    pub fn get(self, k: Value) -> Result<Option<Value>> {
+        let k = await!(Key::from_value(k))?;
+        Ok(self.0.find(&k).map(|v| v.clone()))
+    }
+
     pub fn find(self, k: Value) -> Result<Value> {
-        let n: Normal = await!(k.pured())?;
-        let k: Key = n.try_into()?;
+        let k = await!(Key::from_value(k))?;
 
         match self.0.find(&k).map(|v| v.clone()) {
             Some(v) => Ok(v),
@@ -127,7 +277,7 @@ impl Dictionary {
     }
 
     pub fn delete(self, k: Value) -> Result<Dictionary> {
-        let k: Key = await!(k.pured())?.try_into()?;
+        let k = await!(Key::from_value(k))?;
 
         match self.0.delete(&k) {
             Some(m) => Ok(m.into()),
@@ -136,26 +286,17 @@ impl Dictionary {
     }
 
     pub fn equal(self, d: Self) -> Result<bool> {
-        let kvs1: Vec<(Key, Value)> = self
-            .0
-            .into_iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect();
-
-        let kvs2: Vec<(Key, Value)> = d
-            .0
-            .into_iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect();
-
-        if kvs1.len() != kvs2.len() {
+        if self.size() != d.size() {
             return Ok(false);
         }
 
-        for ((k1, v1), (k2, v2)) in kvs1.into_iter().zip(kvs2.into_iter()) {
-            let k1: Value = k1.into();
+        for (k, v) in self.entries() {
+            let w = match d.0.find(&k) {
+                Some(w) => w.clone(),
+                None => return Ok(false),
+            };
 
-            if !await!(k1.equal(k2.into()))? || !await!(v1.equal(v2))? {
+            if !await!(v.equal(w))? {
                 return Ok(false);
             }
         }
@@ -172,13 +313,189 @@ impl From<Map<Key, Value>> for Dictionary {
 
 #[cfg(test)]
 mod test {
+    use std::collections::hash_map::DefaultHasher;
+    use std::f64;
+
+    use futures::stable::block_on_stable;
+
     use super::*;
 
+    use super::super::utils::TEST_FUNCTION;
+
+    fn key(v: Value) -> Key {
+        block_on_stable(Key::from_value(v)).unwrap()
+    }
+
+    fn hash(k: &Key) -> u64 {
+        let mut h = DefaultHasher::new();
+        k.hash(&mut h);
+        h.finish()
+    }
+
     #[test]
     fn new() {
         Dictionary::new();
     }
 
+    #[test]
+    fn key_from_value() {
+        for v in vec![
+            true.into(),
+            Value::Nil,
+            42.into(),
+            "foo".into(),
+            Symbol::from("foo").into(),
+            List::new(&[1.into(), List::new(&["foo".into()]).into()]).into(),
+            Dictionary::new()
+                .strict_insert("foo", List::new(&[true.into()]))
+                .into(),
+        ]: Vec<Value>
+        {
+            let k = key(v.clone());
+            let n: Normal = k.clone().into();
+
+            assert!(block_on_stable(v.equal(n.clone().into())).unwrap());
+            assert_eq!(key(n.into()), k);
+        }
+    }
+
+    #[test]
+    fn key_from_value_error() {
+        for (v, e) in vec![
+            (TEST_FUNCTION.clone(), "TypeError"),
+            (List::new(&[TEST_FUNCTION.clone()]).into(), "TypeError"),
+            ((0.0 / 0.0).into(), "ValueError"),
+        ]: Vec<(Value, &str)>
+        {
+            let err = block_on_stable(Key::from_value(v)).unwrap_err();
+
+            assert_eq!(err.name(), e);
+            assert!(!err.message().contains("{}"));
+        }
+    }
+
+    #[test]
+    fn key_equality() {
+        for (v, w) in vec![
+            (0.0.into(), (-0.0).into()),
+            (
+                List::new(&[1.into(), 2.into()]).into(),
+                List::new(&[1.into(), 2.into()]).into(),
+            ),
+            (
+                Dictionary::new()
+                    .strict_insert("foo", 1)
+                    .strict_insert("bar", 2)
+                    .into(),
+                Dictionary::new()
+                    .strict_insert("bar", 2)
+                    .strict_insert("foo", 1)
+                    .into(),
+            ),
+        ]: Vec<(Value, Value)>
+        {
+            let (k, l) = (key(v), key(w));
+
+            assert_eq!(k, l);
+            assert_eq!(hash(&k), hash(&l));
+        }
+
+        for (v, w) in vec![
+            (true.into(), 1.into()),
+            (Value::Nil, false.into()),
+            (List::new(&[1.into()]).into(), 1.into()),
+            (List::new(&[1.into()]).into(), List::new(&[2.into()]).into()),
+            ("1".into(), 1.into()),
+            ("foo".into(), Symbol::from("foo").into()),
+            (Symbol::from("foo").into(), Symbol::from("bar").into()),
+        ]: Vec<(Value, Value)>
+        {
+            assert_ne!(key(v), key(w));
+        }
+    }
+
+    #[test]
+    fn key_order() {
+        let nan = Key::from(f64::NAN);
+
+        assert_eq!(nan.cmp(&nan), Ordering::Equal);
+        assert_eq!(nan, nan.clone());
+        assert_eq!(hash(&nan), hash(&nan.clone()));
+
+        let mut ks: Vec<Key> = vec![f64::INFINITY, f64::NAN, 1.5, -1.0, f64::NEG_INFINITY, 0.0]
+            .into_iter()
+            .map(Key::from)
+            .collect();
+
+        ks.sort();
+
+        assert_eq!(
+            ks,
+            vec![f64::NEG_INFINITY, -1.0, 0.0, 1.5, f64::INFINITY, f64::NAN]
+                .into_iter()
+                .map(Key::from)
+                .collect(): Vec<Key>
+        );
+    }
+
+    #[test]
+    fn structural_keys() {
+        let d = Dictionary::new();
+        let l: Value = List::new(&[1.into(), 2.into()]).into();
+
+        let d = block_on_stable(d.insert(true.into(), 1.into())).unwrap();
+        let d = block_on_stable(d.insert(l.clone(), 2.into())).unwrap();
+        let d = block_on_stable(d.insert(0.0.into(), 3.into())).unwrap();
+
+        for (k, v) in vec![
+            (true.into(), 1),
+            (List::new(&[1.into(), 2.into()]).into(), 2),
+            ((-0.0).into(), 3),
+        ]: Vec<(Value, usize)>
+        {
+            assert_eq!(
+                block_on_stable(block_on_stable(d.clone().find(k)).unwrap().number()).unwrap(),
+                v as f64
+            );
+        }
+    }
+
+    #[test]
+    fn equal() {
+        let ns: Vec<usize> = (0..100).collect();
+
+        let d = ns
+            .iter()
+            .fold(Dictionary::new(), |d, n| d.strict_insert(*n as f64, *n));
+        let e = ns
+            .iter()
+            .rev()
+            .fold(Dictionary::new(), |d, n| d.strict_insert(*n as f64, *n));
+
+        assert!(block_on_stable(d.clone().equal(e.clone())).unwrap());
+        assert!(!block_on_stable(d.clone().equal(e.clone().strict_insert(0.0, 1))).unwrap());
+        assert!(!block_on_stable(d.clone().equal(e.strict_insert(100.0, 100))).unwrap());
+        assert!(!block_on_stable(d.equal(Dictionary::new())).unwrap());
+    }
+
+    #[test]
+    fn to_string() {
+        let d = Dictionary::new()
+            .strict_insert("foo", 1)
+            .strict_insert(2.0, 2)
+            .strict_insert(Key::Nil, 3)
+            .strict_insert("bar", 4)
+            .strict_insert(Key::Boolean(true), 5)
+            .strict_insert(Key::Boolean(false), 6)
+            .strict_insert(-1.0, 7)
+            .strict_insert(Key::List(Arc::new(vec![1.0.into()])), 8);
+
+        assert_eq!(
+            block_on_stable(d.to_string()).unwrap(),
+            "{false 6 true 5 [1] 8 nil 3 -1 7 2 2 \"bar\" 4 \"foo\" 1}"
+        );
+    }
+
     #[test]
     fn merge() {
         // TODO: Test filled dictionaries.
@@ -58,6 +58,10 @@ impl Error {
         Self::value("list is empty")
     }
 
+    pub fn index_out_of_range(i: i64) -> Self {
+        Self::value(&format!("index {} is out of range", i))
+    }
+
     pub fn key_not_found(v: Value) -> Result<Self> {
         let s = await!(v.to_string())?;
 
@@ -80,6 +84,10 @@ impl Error {
         await!(Self::typ(v, "boolean".to_string()))
     }
 
+    pub fn not_bytes(v: Value) -> Result<Self> {
+        await!(Self::typ(v, "bytes".to_string()))
+    }
+
     pub fn not_comparable(v: Value, w: Value) -> Result<Self> {
         let s = await!(v.to_string())?;
         let t = await!(w.to_string())?;
@@ -117,9 +125,25 @@ impl Error {
         await!(Self::typ(v, "number".to_string()))
     }
 
+    pub fn not_sequence(v: Value) -> Result<Self> {
+        await!(Self::typ(v, "sequence".to_string()))
+    }
+
+    pub fn not_set(v: Value) -> Result<Self> {
+        await!(Self::typ(v, "set".to_string()))
+    }
+
     pub fn not_string(v: Value) -> Result<Self> {
         await!(Self::typ(v, "string".to_string()))
     }
+
+    pub fn not_symbol(v: Value) -> Result<Self> {
+        await!(Self::typ(v, "symbol".to_string()))
+    }
+
+    pub fn not_vector(v: Value) -> Result<Self> {
+        await!(Self::typ(v, "vector".to_string()))
+    }
 }
 
 impl<E: error::Error> From<E> for Error {
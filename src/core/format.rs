@@ -0,0 +1,355 @@
+use std::convert::TryInto;
+use std::u64;
+
+use super::error::Error;
+use super::normal::Normal;
+use super::result::Result;
+use super::signature::Signature;
+use super::value::Value;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Specification {
+    fill: char,
+    alignment: Option<Alignment>,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    base: Option<char>,
+}
+
+impl Specification {
+    fn parse(s: &str) -> Option<Self> {
+        let cs: Vec<char> = s.chars().collect();
+        let mut i = 0;
+
+        let mut p = Specification {
+            fill: ' ',
+            alignment: None,
+            zero: false,
+            width: 0,
+            precision: None,
+            base: None,
+        };
+
+        if cs.len() >= 2 && alignment(cs[1]).is_some() {
+            p.fill = cs[0];
+            p.alignment = alignment(cs[1]);
+            i = 2;
+        } else if !cs.is_empty() && alignment(cs[0]).is_some() {
+            p.alignment = alignment(cs[0]);
+            i = 1;
+        }
+
+        if cs.get(i) == Some(&'0') {
+            p.zero = true;
+            i += 1;
+        }
+
+        let (w, j) = digits(&cs, i);
+        p.width = w.unwrap_or(0);
+        i = j;
+
+        if cs.get(i) == Some(&'.') {
+            let (n, j) = digits(&cs, i + 1);
+            p.precision = Some(n?);
+            i = j;
+        }
+
+        if let Some(&c) = cs.get(i) {
+            match c {
+                'b' | 'o' | 'x' | 'X' => p.base = Some(c),
+                _ => return None,
+            }
+
+            i += 1;
+        }
+
+        if i == cs.len() {
+            Some(p)
+        } else {
+            None
+        }
+    }
+
+    fn render(&self, v: Value) -> Result<String> {
+        let n = await!(v.pured())?;
+        let m = if let Normal::Number(_) = n { true } else { false };
+
+        let s = match (n, self.base) {
+            (Normal::Number(x), Some(b)) => integer(x, b)?,
+            (n, Some(_)) => return Err(await!(Error::not_number(n.into()))?),
+            (Normal::Number(x), None) => match self.precision {
+                Some(p) => format!("{:.*}", p, x),
+                None => x.to_string(),
+            },
+            (Normal::String(s), None) => {
+                let s: String = s.try_into()?;
+
+                match self.precision {
+                    Some(p) => s.chars().take(p).collect(),
+                    None => s,
+                }
+            }
+            (n, None) => await!(n.to_string())?,
+        };
+
+        Ok(self.pad(s, m))
+    }
+
+    fn pad(&self, s: String, m: bool) -> String {
+        let n = s.chars().count();
+
+        if n >= self.width {
+            return s;
+        }
+
+        let k = self.width - n;
+
+        if m && self.zero && self.alignment.is_none() {
+            let (f, l) = s.split_at(if s.starts_with('-') { 1 } else { 0 });
+            return [f, &"0".repeat(k), l].concat();
+        }
+
+        let a = self.alignment.unwrap_or(if m {
+            Alignment::Right
+        } else {
+            Alignment::Left
+        });
+
+        let (l, r) = match a {
+            Alignment::Left => (0, k),
+            Alignment::Center => (k / 2, k - k / 2),
+            Alignment::Right => (k, 0),
+        };
+
+        let f = self.fill.to_string();
+
+        [f.repeat(l), s, f.repeat(r)].concat()
+    }
+}
+
+fn alignment(c: char) -> Option<Alignment> {
+    match c {
+        '<' => Some(Alignment::Left),
+        '^' => Some(Alignment::Center),
+        '>' => Some(Alignment::Right),
+        _ => None,
+    }
+}
+
+fn digits(cs: &[char], i: usize) -> (Option<usize>, usize) {
+    let j = cs[i.min(cs.len())..]
+        .iter()
+        .position(|c| !c.is_digit(10))
+        .map(|j| i + j)
+        .unwrap_or(cs.len());
+
+    if i >= j {
+        return (None, i);
+    }
+
+    let s: String = cs[i..j].iter().collect();
+    (s.parse().ok(), j)
+}
+
+fn integer(x: f64, b: char) -> Result<String> {
+    if x % 1.0 != 0.0 {
+        return Err(Error::value(&format!("{} is not an integer", x)));
+    } else if x.abs() >= u64::MAX as f64 {
+        return Err(Error::value(&format!("{} is too large to format as an integer", x)));
+    }
+
+    let n = x.abs() as u64;
+
+    let s = match b {
+        'b' => format!("{:b}", n),
+        'o' => format!("{:o}", n),
+        'x' => format!("{:x}", n),
+        _ => format!("{:X}", n),
+    };
+
+    Ok(if x < 0.0 { ["-", &s].concat() } else { s })
+}
+
+pure_function!(
+    FORMAT,
+    Signature::new(
+        vec!["template".into()],
+        "arguments".into(),
+        vec![],
+        "keywords".into()
+    ),
+    format
+);
+
+async fn format(vs: Vec<Value>) -> Result<Value> {
+    let t: String = await!(vs[0].clone().string())?.try_into()?;
+    let ps = await!(await!(vs[1].clone().list())?.to_vec())?;
+    let d = await!(vs[2].clone().dictionary())?;
+
+    let cs: Vec<char> = t.chars().collect();
+    let mut s = String::new();
+    let mut i = 0;
+    let mut n = 0;
+
+    while i < cs.len() {
+        match cs[i] {
+            '{' if cs.get(i + 1) == Some(&'{') => {
+                s.push('{');
+                i += 2;
+            }
+            '}' if cs.get(i + 1) == Some(&'}') => {
+                s.push('}');
+                i += 2;
+            }
+            '{' => {
+                let j = match cs[i..].iter().position(|c| *c == '}') {
+                    Some(j) => i + j,
+                    None => return Err(Error::value("unclosed placeholder in format string")),
+                };
+
+                let f: String = cs[(i + 1)..j].iter().collect();
+                let (k, p) = match f.find(':') {
+                    Some(k) => (&f[..k], &f[(k + 1)..]),
+                    None => (&f[..], ""),
+                };
+
+                let v = if k.is_empty() {
+                    n += 1;
+                    positional(&ps, n - 1)?
+                } else if k.chars().all(|c| c.is_digit(10)) {
+                    positional(&ps, k.parse()?)?
+                } else {
+                    await!(d.clone().find(k.into()))?
+                };
+
+                let p = Specification::parse(p).ok_or_else(|| {
+                    Error::value(&format!("invalid format specification {:?}", p))
+                })?;
+
+                s.push_str(&await!(p.render(v))?);
+                i = j + 1;
+            }
+            '}' => return Err(Error::value("unmatched } in format string")),
+            c => {
+                s.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    Ok(s.into())
+}
+
+fn positional(vs: &[Value], i: usize) -> Result<Value> {
+    vs.get(i)
+        .cloned()
+        .ok_or_else(|| Error::argument(&format!("positional argument {} is missing", i)))
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::arguments::{Arguments, Expansion, KeywordArgument};
+    use super::super::list::List;
+    use super::super::utils::app;
+
+    fn format_with(t: &str, ps: &[Value], ks: &[(&str, Value)]) -> Value {
+        let mut vs = vec![Expansion::Unexpanded(t.into())];
+        vs.extend(ps.iter().map(|v| Expansion::Unexpanded(v.clone())));
+
+        let ks: Vec<Expansion<KeywordArgument>> = ks
+            .iter()
+            .map(|(k, v)| Expansion::Unexpanded(KeywordArgument::new(*k, v.clone())))
+            .collect();
+
+        app(FORMAT.clone(), Arguments::new(&vs, &ks))
+    }
+
+    #[test]
+    fn parse_specification() {
+        for (s, x) in vec![
+            ("", (' ', None, false, 0, None, None)),
+            ("5", (' ', None, false, 5, None, None)),
+            ("<8", (' ', Some(Alignment::Left), false, 8, None, None)),
+            ("*^10.3", ('*', Some(Alignment::Center), false, 10, Some(3), None)),
+            ("08b", (' ', None, true, 8, None, Some('b'))),
+            (".2", (' ', None, false, 0, Some(2), None)),
+            ("->4X", ('-', Some(Alignment::Right), false, 4, None, Some('X'))),
+        ] {
+            let p = Specification::parse(s).unwrap();
+            assert_eq!(
+                (p.fill, p.alignment, p.zero, p.width, p.precision, p.base),
+                x
+            );
+        }
+
+        for s in &["?", "5.", "<<<", "x5", ".2.2"] {
+            assert_eq!(Specification::parse(s), None);
+        }
+    }
+
+    #[test]
+    fn format() {
+        for (t, ps, ks, x) in vec![
+            ("", vec![], vec![], ""),
+            ("foo", vec![], vec![], "foo"),
+            ("{{}}", vec![], vec![], "{}"),
+            ("{} {}", vec![1.into(), "foo".into()], vec![], "1 foo"),
+            ("{1}{0}{1}", vec!["a".into(), "b".into()], vec![], "bab"),
+            ("{name}: {0:.2}", vec![1.23456.into()], vec![("name", "a".into())], "a: 1.23"),
+            ("[{:5}]", vec![42.into()], vec![], "[   42]"),
+            ("[{:5}]", vec!["ab".into()], vec![], "[ab   ]"),
+            ("[{:<5}]", vec![42.into()], vec![], "[42   ]"),
+            ("[{:*^6}]", vec!["ab".into()], vec![], "[**ab**]"),
+            ("[{:^5}]", vec!["日本".into()], vec![], "[ 日本  ]"),
+            ("[{:.2}]", vec!["héllo".into()], vec![], "[hé]"),
+            (
+                "{:b} {:o} {:x} {:X}",
+                vec![5.into(), 8.into(), 255.into(), 255.into()],
+                vec![],
+                "101 10 ff FF",
+            ),
+            ("{:08b}", vec![5.into()], vec![], "00000101"),
+            ("{:05}", vec![(-42.0).into()], vec![], "-0042"),
+            ("{:x}", vec![(-255.0).into()], vec![], "-ff"),
+            ("{}", vec![List::new(&[1.into()]).into()], vec![], "[1]"),
+            ("{x}", vec![], vec![("x", Value::Nil)], "nil"),
+        ]: Vec<(&str, Vec<Value>, Vec<(&str, Value)>, &str)>
+        {
+            assert!(block_on_stable(format_with(t, &ps, &ks).equal(x.into())).unwrap());
+        }
+    }
+
+    #[test]
+    fn format_error() {
+        for (t, ps, e) in vec![
+            ("{", vec![], "ValueError"),
+            ("}", vec![], "ValueError"),
+            ("{:?}", vec![1.into()], "ValueError"),
+            ("{:b}", vec![1.5.into()], "ValueError"),
+            ("{:x}", vec![1e30.into()], "ValueError"),
+            ("{:x}", vec![(-1e30).into()], "ValueError"),
+            ("{:x}", vec!["foo".into()], "TypeError"),
+            ("{} {}", vec![1.into()], "ArgumentError"),
+            ("{1}", vec![1.into()], "ArgumentError"),
+            ("{foo}", vec![], "KeyNotFoundError"),
+        ]: Vec<(&str, Vec<Value>, &str)>
+        {
+            assert_eq!(
+                block_on_stable(format_with(t, &ps, &[]).pured()).unwrap_err().name(),
+                e
+            );
+        }
+    }
+}
@@ -3,10 +3,32 @@ use super::signature::Signature;
 use super::value::Value;
 
 pub use super::boolean::IF;
-pub use super::collection::{INSERT, MERGE};
-pub use super::list::{FIRST, PREPEND, REST};
-pub use super::number::{ADD, DIVIDE, MULTIPLY, SUBTRACT};
+pub use super::bytes::{DECODE, ENCODE};
+pub use super::collection::{
+    CONTAINS, DELETE, GET, INSERT, IS_EMPTY, KEYS, MERGE, SIZE, SLICE, TO_LIST, VALUES,
+};
+pub use super::conversion::{PARSE_NUMBER, TO_BOOLEAN, TO_STRING, TO_SYMBOL};
+pub use super::format::FORMAT;
+pub use super::list::{
+    CONCAT, DROP, FILTER, FIRST, FOLDL, FOLDR, LENGTH, MAP, PREPEND, REST, REVERSE, TAKE, ZIP,
+};
+pub use super::number::{
+    ADD, CHECKED_ADD, CHECKED_DIVIDE, CHECKED_MULTIPLY, CHECKED_SUBTRACT, DIVIDE, MULTIPLY,
+    SUBTRACT,
+};
+pub use super::regex::{REGEX_FIND, REGEX_MATCH, REGEX_REPLACE, REGEX_SPLIT};
+pub use super::sequence::{CYCLE, ITERATE, RANGE, REPEAT};
+pub use super::set::{DIFFERENCE, INTERSECTION, IS_SUBSET, SET_OF, TO_SET, UNION};
+pub use super::sort::{SORT, SORT_BY};
+pub use super::string::{
+    CHARS, ENDS_WITH, INDEX_OF, JOIN, LOWER, REPLACE, SPLIT, STARTS_WITH, TRIM, UPPER,
+};
+pub use super::types::{
+    IS_BOOLEAN, IS_BYTES, IS_DICTIONARY, IS_FUNCTION, IS_LIST, IS_NIL, IS_NUMBER, IS_SET,
+    IS_STRING, IS_SYMBOL, IS_VECTOR, TYPE_OF,
+};
 pub use super::utils::IDENTITY;
+pub use super::vector::{PUSH, SET, TO_VECTOR, VECTOR};
 
 pure_function!(
     EQUAL,
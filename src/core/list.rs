@@ -7,9 +7,10 @@ use futures::prelude::*;
 use super::arguments::{Arguments, Expansion};
 use super::collection::MERGE;
 use super::error::Error;
+use super::normal::Normal;
 use super::result::Result;
 use super::signature::Signature;
-use super::utils::app;
+use super::utils::{app, papp};
 use super::value::Value;
 
 #[derive(Clone, Debug)]
@@ -83,6 +84,31 @@ impl List {
         }
     }
 
+    pub fn delete(mut self, i: usize) -> Result<List> {
+        let mut vs = vec![];
+
+        for _ in 1..i {
+            if self.is_empty() {
+                return Err(Error::index_out_of_range(i as i64));
+            }
+
+            vs.push(self.first()?);
+            self = await!(self.rest())?;
+        }
+
+        if self.is_empty() {
+            return Err(Error::index_out_of_range(i as i64));
+        }
+
+        let mut l = await!(self.rest())?;
+
+        for v in vs.into_iter().rev() {
+            l = Self::cons(v, l);
+        }
+
+        Ok(l)
+    }
+
     pub fn merge(self, v: Value) -> Result<Value> {
         if await!(v.clone().list())?.is_empty() {
             return Ok(self.clone().into());
@@ -115,6 +141,17 @@ impl List {
         }
     }
 
+    pub fn to_vec(mut self) -> Result<Vec<Value>> {
+        let mut vs = vec![];
+
+        while !self.is_empty() {
+            vs.push(self.first()?);
+            self = await!(self.rest())?;
+        }
+
+        Ok(vs)
+    }
+
     pub fn to_string(mut self) -> Result<String> {
         let mut ss = vec![];
 
@@ -231,13 +268,278 @@ async fn prepend(vs: Vec<Value>) -> Result<Value> {
     ).into())
 }
 
+pure_function!(
+    MAP,
+    Signature::new(
+        vec!["function".into(), "list".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    map
+);
+
+async fn map(vs: Vec<Value>) -> Result<Value> {
+    let f = vs[0].clone();
+
+    Ok(match await!(vs[1].clone().list())? {
+        List::Empty => List::Empty,
+        List::Cons(c) => {
+            let Cons(x, r) = (*c).clone();
+            List::cons(papp(f.clone(), &[x]), papp(MAP.clone(), &[f, r]))
+        }
+    }.into())
+}
+
+pure_function!(
+    FILTER,
+    Signature::new(
+        vec!["function".into(), "list".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    filter
+);
+
+async fn filter(vs: Vec<Value>) -> Result<Value> {
+    let f = vs[0].clone();
+    let mut l = await!(vs[1].clone().list())?;
+
+    while let List::Cons(c) = l {
+        let Cons(x, r) = (*c).clone();
+
+        if await!(papp(f.clone(), &[x.clone()]).boolean())? {
+            return Ok(List::cons(x, papp(FILTER.clone(), &[f, r])).into());
+        }
+
+        l = await!(r.list())?;
+    }
+
+    Ok(List::Empty.into())
+}
+
+pure_function!(
+    FOLDL,
+    Signature::new(
+        vec!["function".into(), "initial".into(), "list".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    foldl
+);
+
+async fn foldl(vs: Vec<Value>) -> Result<Value> {
+    let f = vs[0].clone();
+    let mut a = vs[1].clone();
+    let mut l = await!(vs[2].clone().list())?;
+
+    while let List::Cons(c) = l {
+        let Cons(x, r) = (*c).clone();
+        a = await!(papp(f.clone(), &[a, x]).pured())?.into();
+        l = await!(r.list())?;
+    }
+
+    Ok(a)
+}
+
+pure_function!(
+    FOLDR,
+    Signature::new(
+        vec!["function".into(), "initial".into(), "list".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    foldr
+);
+
+async fn foldr(vs: Vec<Value>) -> Result<Value> {
+    let f = vs[0].clone();
+    let a = vs[1].clone();
+
+    Ok(match await!(vs[2].clone().list())? {
+        List::Empty => a,
+        List::Cons(c) => {
+            let Cons(x, r) = (*c).clone();
+            papp(f.clone(), &[x, papp(FOLDR.clone(), &[f, a, r])])
+        }
+    })
+}
+
+pure_function!(
+    LENGTH,
+    Signature::new(vec!["sequence".into()], "".into(), vec![], "".into()),
+    length
+);
+
+async fn length(vs: Vec<Value>) -> Result<Value> {
+    let mut l = match await!(vs[0].clone().pured())? {
+        Normal::Bytes(bs) => return Ok(bs.len().into()),
+        Normal::List(l) => l,
+        Normal::String(s) => return Ok(s.len().into()),
+        n => return Err(await!(Error::not_sequence(n.into()))?),
+    };
+
+    let mut n: usize = 0;
+
+    while !l.is_empty() {
+        n += 1;
+        l = await!(l.rest())?;
+    }
+
+    Ok(n.into())
+}
+
+pure_function!(
+    REVERSE,
+    Signature::new(vec!["list".into()], "".into(), vec![], "".into()),
+    reverse
+);
+
+async fn reverse(vs: Vec<Value>) -> Result<Value> {
+    let mut l = await!(vs[0].clone().list())?;
+    let mut m = List::Empty;
+
+    while let List::Cons(c) = l {
+        let Cons(x, r) = (*c).clone();
+        m = List::cons(x, m);
+        l = await!(r.list())?;
+    }
+
+    Ok(m.into())
+}
+
+pure_function!(
+    TAKE,
+    Signature::new(
+        vec!["number".into(), "list".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    take
+);
+
+async fn take(vs: Vec<Value>) -> Result<Value> {
+    let n = await!(vs[0].clone().natural())?;
+
+    if n == 0 {
+        return Ok(List::Empty.into());
+    }
+
+    Ok(match await!(vs[1].clone().list())? {
+        List::Empty => List::Empty,
+        List::Cons(c) => {
+            let Cons(x, r) = (*c).clone();
+            List::cons(x, papp(TAKE.clone(), &[(n - 1).into(), r]))
+        }
+    }.into())
+}
+
+pure_function!(
+    DROP,
+    Signature::new(
+        vec!["number".into(), "list".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    drop
+);
+
+async fn drop(vs: Vec<Value>) -> Result<Value> {
+    let n = await!(vs[0].clone().natural())?;
+    let mut l = await!(vs[1].clone().list())?;
+
+    for _ in 0..n {
+        if l.is_empty() {
+            break;
+        }
+
+        l = await!(l.rest())?;
+    }
+
+    Ok(l.into())
+}
+
+pure_function!(
+    ZIP,
+    Signature::new(vec![], "lists".into(), vec![], "".into()),
+    zip
+);
+
+async fn zip(vs: Vec<Value>) -> Result<Value> {
+    let mut ls = await!(vs[0].clone().list())?;
+    let mut xs = vec![];
+    let mut rs = vec![];
+
+    while let List::Cons(c) = ls {
+        let Cons(l, r) = (*c).clone();
+
+        match await!(l.list())? {
+            List::Empty => return Ok(List::Empty.into()),
+            List::Cons(c) => {
+                let Cons(x, r) = (*c).clone();
+                xs.push(x);
+                rs.push(r);
+            }
+        }
+
+        ls = await!(r.list())?;
+    }
+
+    if xs.is_empty() {
+        return Ok(List::Empty.into());
+    }
+
+    Ok(List::cons(
+        List::new(&xs),
+        app(
+            ZIP.clone(),
+            Arguments::new(&[Expansion::Expanded(List::new(&rs).into())], &[]),
+        ),
+    ).into())
+}
+
+pure_function!(
+    CONCAT,
+    Signature::new(vec![], "lists".into(), vec![], "".into()),
+    concat
+);
+
+async fn concat(vs: Vec<Value>) -> Result<Value> {
+    let mut ls = await!(vs[0].clone().list())?;
+
+    while let List::Cons(c) = ls {
+        let Cons(l, r) = (*c).clone();
+
+        if let List::Cons(c) = await!(l.list())? {
+            let Cons(x, rr) = (*c).clone();
+            return Ok(List::cons(
+                x,
+                app(
+                    CONCAT.clone(),
+                    Arguments::new(&[Expansion::Expanded(List::cons(rr, r).into())], &[]),
+                ),
+            ).into());
+        }
+
+        ls = await!(r.list())?;
+    }
+
+    Ok(List::Empty.into())
+}
+
 #[cfg(test)]
 mod test {
     use futures::stable::block_on_stable;
 
     use super::*;
 
-    use super::super::utils::papp;
+    use super::super::function::Function;
+    use super::super::number::ADD;
 
     #[test]
     fn new() {
@@ -246,6 +548,22 @@ mod test {
         List::new(&[42.into(), 42.into()]);
     }
 
+    #[test]
+    fn delete() {
+        let l = List::new(&[0.into(), 1.into(), 2.into()]);
+
+        for (i, x) in vec![
+            (1, List::new(&[1.into(), 2.into()])),
+            (2, List::new(&[0.into(), 2.into()])),
+            (3, List::new(&[0.into(), 1.into()])),
+        ] {
+            let l = block_on_stable(l.clone().delete(i)).unwrap();
+            assert!(block_on_stable(l.equal(x)).unwrap());
+        }
+
+        assert!(block_on_stable(l.delete(4)).is_err());
+    }
+
     #[test]
     fn first() {
         let n = block_on_stable(papp(FIRST.clone(), &[List::new(&[42.into()]).into()]).number())
@@ -277,4 +595,234 @@ mod test {
     fn from_slice() {
         List::from(&[42.0.into()] as &[Value]);
     }
+
+    pure_function!(
+        NATURALS,
+        Signature::new(vec!["n".into()], "".into(), vec![], "".into()),
+        naturals
+    );
+
+    async fn naturals(vs: Vec<Value>) -> Result<Value> {
+        let n = await!(vs[0].clone().number())?;
+        Ok(List::cons(n, papp(NATURALS.clone(), &[(n + 1.0).into()])).into())
+    }
+
+    pure_function!(
+        IS_EVEN,
+        Signature::new(vec!["n".into()], "".into(), vec![], "".into()),
+        is_even
+    );
+
+    async fn is_even(vs: Vec<Value>) -> Result<Value> {
+        Ok((await!(vs[0].clone().number())? % 2.0 == 0.0).into())
+    }
+
+    fn increment() -> Value {
+        Function::closure(ADD.clone(), Arguments::positionals(&[1.into()])).into()
+    }
+
+    fn naturals_list() -> Value {
+        papp(NATURALS.clone(), &[0.into()])
+    }
+
+    fn numbers(ns: &[usize]) -> Value {
+        List::new(&(ns.iter().map(|n| Value::from(*n)).collect(): Vec<Value>)).into()
+    }
+
+    #[test]
+    fn map() {
+        for (l, x) in vec![
+            (List::Empty.into(), List::Empty.into()),
+            (numbers(&[1, 2, 3]), numbers(&[2, 3, 4])),
+            (
+                papp(TAKE.clone(), &[3.into(), naturals_list()]),
+                numbers(&[1, 2, 3]),
+            ),
+        ]: Vec<(Value, Value)>
+        {
+            assert!(block_on_stable(papp(MAP.clone(), &[increment(), l]).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn map_infinite_list() {
+        let l = papp(MAP.clone(), &[increment(), naturals_list()]);
+
+        assert!(
+            block_on_stable(papp(TAKE.clone(), &[3.into(), l]).equal(numbers(&[1, 2, 3]))).unwrap()
+        );
+    }
+
+    #[test]
+    fn map_lazy_elements() {
+        let e = papp(FIRST.clone(), &[List::Empty.into()]);
+        let l = papp(MAP.clone(), &[increment(), List::new(&[e, 1.into()]).into()]);
+
+        assert!(block_on_stable(papp(LENGTH.clone(), &[l]).equal(2.into())).unwrap());
+    }
+
+    #[test]
+    fn filter() {
+        for (l, x) in vec![
+            (List::Empty.into(), List::Empty.into()),
+            (numbers(&[1, 3]), List::Empty.into()),
+            (numbers(&[1, 2, 3, 4]), numbers(&[2, 4])),
+        ]: Vec<(Value, Value)>
+        {
+            assert!(block_on_stable(papp(FILTER.clone(), &[IS_EVEN.clone(), l]).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn filter_infinite_list() {
+        let l = papp(FILTER.clone(), &[IS_EVEN.clone(), naturals_list()]);
+
+        assert!(
+            block_on_stable(papp(TAKE.clone(), &[3.into(), l]).equal(numbers(&[0, 2, 4]))).unwrap()
+        );
+    }
+
+    #[test]
+    fn foldl() {
+        for (l, x) in vec![
+            (List::Empty.into(), 0),
+            (numbers(&[1, 2, 3]), 6),
+        ]: Vec<(Value, usize)>
+        {
+            assert!(
+                block_on_stable(papp(FOLDL.clone(), &[ADD.clone(), 0.into(), l]).equal(x.into()))
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn foldr() {
+        for l in vec![List::Empty.into(), numbers(&[1, 2, 3])]: Vec<Value> {
+            assert!(
+                block_on_stable(
+                    papp(FOLDR.clone(), &[PREPEND.clone(), List::Empty.into(), l.clone()]).equal(l)
+                ).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn foldr_infinite_list() {
+        let l = papp(
+            FOLDR.clone(),
+            &[PREPEND.clone(), List::Empty.into(), naturals_list()],
+        );
+
+        assert!(
+            block_on_stable(papp(TAKE.clone(), &[3.into(), l]).equal(numbers(&[0, 1, 2]))).unwrap()
+        );
+    }
+
+    #[test]
+    fn length() {
+        for (l, n) in vec![
+            (List::Empty.into(), 0),
+            (numbers(&[1]), 1),
+            (numbers(&[1, 2, 3]), 3),
+            ("".into(), 0),
+            ("héllo".into(), 5),
+            ("日本語".into(), 3),
+        ]: Vec<(Value, usize)>
+        {
+            assert!(block_on_stable(papp(LENGTH.clone(), &[l]).equal(n.into())).unwrap());
+        }
+
+        assert!(block_on_stable(papp(LENGTH.clone(), &[42.into()]).pured()).is_err());
+    }
+
+    #[test]
+    fn reverse() {
+        for (l, x) in vec![
+            (List::Empty.into(), List::Empty.into()),
+            (numbers(&[1]), numbers(&[1])),
+            (numbers(&[1, 2, 3]), numbers(&[3, 2, 1])),
+        ]: Vec<(Value, Value)>
+        {
+            assert!(block_on_stable(papp(REVERSE.clone(), &[l]).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn take() {
+        for (n, l, x) in vec![
+            (0, numbers(&[1, 2]), List::Empty.into()),
+            (1, numbers(&[1, 2]), numbers(&[1])),
+            (3, numbers(&[1, 2]), numbers(&[1, 2])),
+            (2, List::Empty.into(), List::Empty.into()),
+        ]: Vec<(usize, Value, Value)>
+        {
+            assert!(block_on_stable(papp(TAKE.clone(), &[n.into(), l]).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn take_error() {
+        for n in vec![(-1.0).into(), 0.5.into(), "foo".into()]: Vec<Value> {
+            assert!(
+                block_on_stable(papp(TAKE.clone(), &[n, List::Empty.into()]).pured()).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn drop() {
+        for (n, l, x) in vec![
+            (0, numbers(&[1, 2]), numbers(&[1, 2])),
+            (1, numbers(&[1, 2]), numbers(&[2])),
+            (3, numbers(&[1, 2]), List::Empty.into()),
+        ]: Vec<(usize, Value, Value)>
+        {
+            assert!(block_on_stable(papp(DROP.clone(), &[n.into(), l]).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn zip() {
+        for (ls, x) in vec![
+            (vec![], List::Empty.into()),
+            (vec![numbers(&[1, 2])], List::new(&[numbers(&[1]), numbers(&[2])]).into()),
+            (
+                vec![numbers(&[1, 2, 3]), numbers(&[4, 5])],
+                List::new(&[numbers(&[1, 4]), numbers(&[2, 5])]).into(),
+            ),
+            (
+                vec![numbers(&[1, 2]), naturals_list()],
+                List::new(&[numbers(&[1, 0]), numbers(&[2, 1])]).into(),
+            ),
+            (vec![numbers(&[1]), List::Empty.into()], List::Empty.into()),
+        ]: Vec<(Vec<Value>, Value)>
+        {
+            assert!(block_on_stable(papp(ZIP.clone(), &ls).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn concat() {
+        for (ls, x) in vec![
+            (vec![], List::Empty.into()),
+            (vec![List::Empty.into(), List::Empty.into()], List::Empty.into()),
+            (
+                vec![numbers(&[1]), List::Empty.into(), numbers(&[2, 3])],
+                numbers(&[1, 2, 3]),
+            ),
+        ]: Vec<(Vec<Value>, Value)>
+        {
+            assert!(block_on_stable(papp(CONCAT.clone(), &ls).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn concat_infinite_list() {
+        let l = papp(CONCAT.clone(), &[numbers(&[1]), naturals_list()]);
+
+        assert!(
+            block_on_stable(papp(TAKE.clone(), &[3.into(), l]).equal(numbers(&[1, 0, 1]))).unwrap()
+        );
+    }
 }
@@ -2,29 +2,45 @@
 mod function;
 mod arguments;
 mod boolean;
+mod bytes;
 mod collection;
+mod conversion;
 mod dictionary;
 mod error;
+mod format;
 pub mod functions;
 mod list;
 mod normal;
 mod number;
 mod parameters;
+mod regex;
 mod result;
+mod sequence;
+mod set;
 mod signature;
+mod sort;
 mod string;
+mod symbol;
 mod thunk;
+mod types;
 mod unsafe_ref;
 mod utils;
 mod value;
+mod vector;
 
 pub use self::arguments::{Arguments, Expansion, KeywordArgument};
+pub use self::bytes::Bytes;
 pub use self::dictionary::Dictionary;
+pub use self::error::Error;
 pub use self::function::{Function, Result};
 pub use self::list::List;
 pub use self::normal::Normal;
 pub use self::parameters::OptionalParameter;
+pub use self::set::Set;
 pub use self::signature::Signature;
 pub use self::string::Str;
+pub use self::symbol::Symbol;
+pub use self::thunk::BlackHole;
 pub use self::utils::{app, papp};
 pub use self::value::Value;
+pub use self::vector::Vector;
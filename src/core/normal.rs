@@ -4,57 +4,77 @@ use std::fmt::{self, Debug, Formatter};
 
 use futures::prelude::*;
 
+use super::bytes::Bytes;
 use super::dictionary::Dictionary;
 use super::error::Error;
 use super::function::Function;
 use super::list::List;
 use super::result::Result;
+use super::set::Set;
 use super::string::Str;
+use super::symbol::Symbol;
+use super::vector::Vector;
 
 #[derive(Clone)]
 pub enum Normal {
     Boolean(bool),
+    Bytes(Bytes),
     Dictionary(Dictionary),
     Function(Function),
     List(List),
     Nil,
     Number(f64),
+    Set(Set),
     String(Str),
+    Symbol(Symbol),
+    Vector(Vector),
 }
 
 impl Normal {
     pub fn to_string(self) -> Result<String> {
         Ok(match self {
             Normal::Boolean(b) => (if b { "true" } else { "false" }).to_string(),
+            Normal::Bytes(bs) => bs.to_string(),
             Normal::Dictionary(d) => await!(d.to_string())?,
             Normal::Function(_) => "<function>".to_string(),
             Normal::List(l) => await!(l.to_string())?,
             Normal::Number(n) => n.to_string(),
             Normal::Nil => "nil".to_string(),
+            Normal::Set(s) => await!(s.to_string())?,
             Normal::String(s) => ["\"".to_string(), s.try_into()?, "\"".to_string()].concat(),
+            Normal::Symbol(s) => [":", s.as_str()].concat(),
+            Normal::Vector(v) => await!(v.to_string())?,
         })
     }
 
     pub fn type_name(&self) -> Str {
         match *self {
             Normal::Boolean(_) => "boolean",
+            Normal::Bytes(_) => "bytes",
             Normal::Dictionary(_) => "dictionary",
             Normal::Function(_) => "function",
             Normal::List(_) => "list",
             Normal::Number(_) => "number",
             Normal::Nil => "nil",
+            Normal::Set(_) => "set",
             Normal::String(_) => "string",
+            Normal::Symbol(_) => "symbol",
+            Normal::Vector(_) => "vector",
         }.into()
     }
 
     pub fn equal(self, n: Self) -> Result<bool> {
         Ok(match (self.clone(), n.clone()) {
             (Normal::Boolean(x), Normal::Boolean(y)) => x == y,
+            (Normal::Bytes(x), Normal::Bytes(y)) => x == y,
             (Normal::Dictionary(x), Normal::Dictionary(y)) => await!(x.equal(y))?,
             (Normal::List(x), Normal::List(y)) => await!(x.equal(y))?,
             (Normal::Number(x), Normal::Number(y)) => x == y,
             (Normal::Nil, Normal::Nil) => true,
+            (Normal::Set(x), Normal::Set(y)) => await!(x.equal(y))?,
             (Normal::String(x), Normal::String(y)) => x == y,
+            (Normal::Symbol(x), Normal::Symbol(y)) => x == y,
+            (Normal::Vector(x), Normal::Vector(y)) => await!(x.equal(y))?,
             (Normal::Function(f), _) => return Err(await!(Error::not_equalable(f.into()))?),
             (_, Normal::Function(f)) => return Err(await!(Error::not_equalable(f.into()))?),
             _ => false,
@@ -63,6 +83,7 @@ impl Normal {
 
     pub fn compare(self, n: Self) -> Result<Ordering> {
         Ok(match (self, n) {
+            (Normal::Bytes(x), Normal::Bytes(y)) => x.cmp(&y),
             (Normal::List(x), Normal::List(y)) => await!(x.compare(y))?,
             (Normal::Number(x), Normal::Number(y)) => if let Some(o) = x.partial_cmp(&y) {
                 o
@@ -70,6 +91,8 @@ impl Normal {
                 return Err(await!(Error::not_comparable(x.into(), y.into()))?);
             },
             (Normal::String(x), Normal::String(y)) => x.cmp(&y),
+            (Normal::Symbol(x), Normal::Symbol(y)) => x.cmp(&y),
+            (Normal::Vector(x), Normal::Vector(y)) => await!(x.compare(y))?,
             (x, y) => return Err(await!(Error::not_comparable(x.into(), y.into()))?),
         })
     }
@@ -90,6 +113,12 @@ impl From<bool> for Normal {
     }
 }
 
+impl From<Bytes> for Normal {
+    fn from(bs: Bytes) -> Self {
+        Normal::Bytes(bs)
+    }
+}
+
 impl From<Dictionary> for Normal {
     fn from(d: Dictionary) -> Self {
         Normal::Dictionary(d)
@@ -120,6 +149,24 @@ impl From<usize> for Normal {
     }
 }
 
+impl From<Set> for Normal {
+    fn from(s: Set) -> Self {
+        Normal::Set(s)
+    }
+}
+
+impl From<Symbol> for Normal {
+    fn from(s: Symbol) -> Self {
+        Normal::Symbol(s)
+    }
+}
+
+impl From<Vector> for Normal {
+    fn from(v: Vector) -> Self {
+        Normal::Vector(v)
+    }
+}
+
 impl<S: Into<Str>> From<S> for Normal {
     fn from(s: S) -> Self {
         Normal::String(s.into())
@@ -136,11 +183,15 @@ mod test {
     fn size() {
         for s in vec![
             size_of::<bool>(),
+            size_of::<Bytes>(),
             size_of::<Dictionary>(),
             size_of::<Function>(),
             size_of::<List>(),
             size_of::<f64>(),
+            size_of::<Set>(),
             size_of::<Str>(),
+            size_of::<Symbol>(),
+            size_of::<Vector>(),
         ] {
             assert!(s <= 2 * size_of::<usize>());
         }
@@ -1,26 +1,85 @@
+use super::error::Error;
 use super::result::Result;
 use super::signature::Signature;
 use super::value::Value;
 
-pure_function!(
-    ADD,
-    Signature::new(vec![], "ns".into(), vec![], "".into()),
-    add
-);
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
 
-async fn add(vs: Vec<Value>) -> Result<Value> {
-    let mut l = await!(vs[0].clone().list())?;
-    let mut n = 0.0;
+impl Operation {
+    fn name(self) -> &'static str {
+        match self {
+            Operation::Add => "addition",
+            Operation::Subtract => "subtraction",
+            Operation::Multiply => "multiplication",
+            Operation::Divide => "division",
+        }
+    }
+
+    fn identity(self) -> Option<f64> {
+        match self {
+            Operation::Add => Some(0.0),
+            Operation::Multiply => Some(1.0),
+            Operation::Subtract | Operation::Divide => None,
+        }
+    }
+
+    fn apply(self, x: f64, y: f64) -> f64 {
+        match self {
+            Operation::Add => x + y,
+            Operation::Subtract => x - y,
+            Operation::Multiply => x * y,
+            Operation::Divide => x / y,
+        }
+    }
+}
+
+fn arithmetic(o: Operation, v: Value, checked: bool) -> Result<Value> {
+    let mut l = await!(v.clone().list())?;
+
+    let mut n = match o.identity() {
+        Some(n) => n,
+        None => {
+            let n = await!(l.first()?.number())?;
+            l = await!(l.rest())?;
+            n
+        }
+    };
 
     while !l.is_empty() {
         let m = await!(l.first()?.number())?;
-        n += m;
+        n = o.apply(n, m);
+
+        if checked && !n.is_finite() {
+            return Err(Error::value(&format!(
+                "{} of {} resulted in {}",
+                o.name(),
+                await!(v.to_string())?,
+                n
+            )));
+        }
+
         l = await!(l.rest())?;
     }
 
     Ok(n.into())
 }
 
+pure_function!(
+    ADD,
+    Signature::new(vec![], "ns".into(), vec![], "".into()),
+    add
+);
+
+async fn add(vs: Vec<Value>) -> Result<Value> {
+    await!(arithmetic(Operation::Add, vs[0].clone(), false))
+}
+
 pure_function!(
     SUBTRACT,
     Signature::new(vec![], "ns".into(), vec![], "".into()),
@@ -28,17 +87,7 @@ pure_function!(
 );
 
 async fn subtract(vs: Vec<Value>) -> Result<Value> {
-    let mut l = await!(vs[0].clone().list())?;
-    let mut n = await!(l.first()?.number())?;
-    l = await!(l.rest())?;
-
-    while !l.is_empty() {
-        let m = await!(l.first()?.number())?;
-        n -= m;
-        l = await!(l.rest())?;
-    }
-
-    Ok(n.into())
+    await!(arithmetic(Operation::Subtract, vs[0].clone(), false))
 }
 
 pure_function!(
@@ -48,16 +97,7 @@ pure_function!(
 );
 
 async fn multiply(vs: Vec<Value>) -> Result<Value> {
-    let mut l = await!(vs[0].clone().list())?;
-    let mut n: f64 = 1.0;
-
-    while !l.is_empty() {
-        let m = await!(l.first()?.number())?;
-        n *= m;
-        l = await!(l.rest())?;
-    }
-
-    Ok(n.into())
+    await!(arithmetic(Operation::Multiply, vs[0].clone(), false))
 }
 
 pure_function!(
@@ -67,17 +107,47 @@ pure_function!(
 );
 
 async fn divide(vs: Vec<Value>) -> Result<Value> {
-    let mut l = await!(vs[0].clone().list())?;
-    let mut n = await!(l.first()?.number())?;
-    l = await!(l.rest())?;
+    await!(arithmetic(Operation::Divide, vs[0].clone(), false))
+}
 
-    while !l.is_empty() {
-        let m = await!(l.first()?.number())?;
-        n /= m;
-        l = await!(l.rest())?;
-    }
+pure_function!(
+    CHECKED_ADD,
+    Signature::new(vec![], "ns".into(), vec![], "".into()),
+    checked_add
+);
 
-    Ok(n.into())
+async fn checked_add(vs: Vec<Value>) -> Result<Value> {
+    await!(arithmetic(Operation::Add, vs[0].clone(), true))
+}
+
+pure_function!(
+    CHECKED_SUBTRACT,
+    Signature::new(vec![], "ns".into(), vec![], "".into()),
+    checked_subtract
+);
+
+async fn checked_subtract(vs: Vec<Value>) -> Result<Value> {
+    await!(arithmetic(Operation::Subtract, vs[0].clone(), true))
+}
+
+pure_function!(
+    CHECKED_MULTIPLY,
+    Signature::new(vec![], "ns".into(), vec![], "".into()),
+    checked_multiply
+);
+
+async fn checked_multiply(vs: Vec<Value>) -> Result<Value> {
+    await!(arithmetic(Operation::Multiply, vs[0].clone(), true))
+}
+
+pure_function!(
+    CHECKED_DIVIDE,
+    Signature::new(vec![], "ns".into(), vec![], "".into()),
+    checked_divide
+);
+
+async fn checked_divide(vs: Vec<Value>) -> Result<Value> {
+    await!(arithmetic(Operation::Divide, vs[0].clone(), true))
 }
 
 #[cfg(test)]
@@ -155,4 +225,57 @@ mod test {
     fn divide_error() {
         assert!(block_on_stable(papp(DIVIDE.clone(), &[]).number()).is_err());
     }
+
+    #[test]
+    fn divide_non_finite() {
+        for xs in vec![&[1.into(), 0.into()], &[0.into(), 0.into()]]: Vec<&[Value]> {
+            let n = block_on_stable(papp(DIVIDE.clone(), xs).number()).unwrap();
+            assert!(!n.is_finite());
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        for (f, xs, y) in vec![
+            (CHECKED_ADD.clone(), &[1.into(), 2.into()], 3.0),
+            (CHECKED_SUBTRACT.clone(), &[1.into(), 2.into()], -1.0),
+            (CHECKED_MULTIPLY.clone(), &[2.into(), 3.into()], 6.0),
+            (CHECKED_DIVIDE.clone(), &[1.into(), 2.into()], 0.5),
+        ]: Vec<(Value, &[Value], f64)>
+        {
+            assert_eq!(block_on_stable(papp(f, xs).number()).unwrap(), y);
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic_error() {
+        for (f, xs, m) in vec![
+            (
+                CHECKED_DIVIDE.clone(),
+                &[1.into(), 0.into()],
+                "division of [1 0] resulted in inf",
+            ),
+            (
+                CHECKED_DIVIDE.clone(),
+                &[0.into(), 0.into()],
+                "division of [0 0] resulted in NaN",
+            ),
+        ]: Vec<(Value, &[Value], &str)>
+        {
+            let e = block_on_stable(papp(f, xs).number()).unwrap_err();
+
+            assert_eq!(e.name(), "ValueError");
+            assert_eq!(e.message(), m);
+        }
+
+        for (f, xs) in vec![
+            (CHECKED_ADD.clone(), &[1e308.into(), 1e308.into()]),
+            (CHECKED_SUBTRACT.clone(), &[(-1e308).into(), 1e308.into()]),
+            (CHECKED_MULTIPLY.clone(), &[1e308.into(), 10.into(), 0.into()]),
+        ]: Vec<(Value, &[Value])>
+        {
+            let e = block_on_stable(papp(f, xs).number()).unwrap_err();
+            assert_eq!(e.name(), "ValueError");
+        }
+    }
 }
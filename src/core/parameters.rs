@@ -2,6 +2,7 @@ use super::arguments::Arguments;
 use super::error::Error;
 use super::result::Result;
 use super::string::Str;
+use super::symbol::Symbol;
 use super::unsafe_ref::{Ref, RefMut};
 use super::value::Value;
 
@@ -79,12 +80,12 @@ impl KeywordParameters {
 
 #[derive(Clone, Debug)]
 pub struct OptionalParameter {
-    pub name: Str,
+    pub name: Symbol,
     pub value: Value,
 }
 
 impl OptionalParameter {
-    pub fn new(n: impl Into<Str>, v: impl Into<Value>) -> Self {
+    pub fn new(n: impl Into<Symbol>, v: impl Into<Value>) -> Self {
         OptionalParameter {
             name: n.into(),
             value: v.into(),
@@ -201,4 +202,28 @@ mod test {
             )).unwrap();
         });
     }
+
+    #[bench]
+    fn bench_keyword_parameters_bind_many(b: &mut Bencher) {
+        let ns = ["foo", "bar", "baz", "qux"];
+        let ks = KeywordParameters::new(
+            ns.iter().map(|n| OptionalParameter::new(*n, 42)).collect(),
+            "".into(),
+        );
+        let a = Arguments::new(
+            &[],
+            &(ns.iter()
+                .rev()
+                .map(|n| Expansion::Unexpanded(KeywordArgument::new(*n, 42)))
+                .collect(): Vec<Expansion<KeywordArgument>>),
+        );
+
+        b.iter(|| {
+            let mut a = a.clone();
+            block_on_stable(Ref(&ks).bind(
+                (&mut a).into(),
+                (&mut Vec::with_capacity(ks.arity())).into(),
+            )).unwrap();
+        });
+    }
 }
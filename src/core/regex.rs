@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::sync::Mutex;
+
+use regex::{self, Captures, Regex};
+use regex_syntax;
+
+use super::dictionary::Dictionary;
+use super::error::Error;
+use super::list::List;
+use super::result::Result;
+use super::signature::Signature;
+use super::value::Value;
+
+const CACHE_CAPACITY: usize = 256;
+
+lazy_static! {
+    static ref CACHE: Mutex<HashMap<String, Regex>> = Mutex::new(HashMap::new());
+}
+
+fn compile(p: &str) -> Result<Regex> {
+    let mut c = CACHE.lock().unwrap();
+
+    if let Some(r) = c.get(p) {
+        return Ok(r.clone());
+    }
+
+    let r = Regex::new(p).map_err(|e| invalid_pattern(p, e))?;
+
+    if c.len() >= CACHE_CAPACITY {
+        c.clear();
+    }
+
+    c.insert(p.into(), r.clone());
+
+    Ok(r)
+}
+
+fn invalid_pattern(p: &str, e: regex::Error) -> Error {
+    let (i, m) = match regex_syntax::Parser::new().parse(p) {
+        Err(regex_syntax::Error::Parse(e)) => (e.span().start.offset, e.kind().to_string()),
+        Err(regex_syntax::Error::Translate(e)) => (e.span().start.offset, e.kind().to_string()),
+        _ => return Error::value(&format!("invalid regular expression {:?}: {}", p, e)),
+    };
+
+    Error::value(&format!(
+        "invalid regular expression {:?} at position {}: {}",
+        p,
+        p[..i].chars().count() + 1,
+        m
+    ))
+}
+
+fn arguments(s: Value, p: Value) -> Result<(String, Regex)> {
+    let s: String = await!(s.string())?.try_into()?;
+    let p: String = await!(p.string())?.try_into()?;
+
+    Ok((s, compile(&p)?))
+}
+
+fn captures(r: &Regex, cs: Captures) -> Value {
+    let v = |i: usize| cs.get(i).map(|m| m.as_str().into()).unwrap_or(Value::Nil);
+
+    if r.capture_names().any(|n| n.is_some()) {
+        let mut d = Dictionary::new();
+
+        for (i, n) in r.capture_names().enumerate() {
+            if let Some(n) = n {
+                d = d.strict_insert(n, v(i));
+            }
+        }
+
+        d.into()
+    } else {
+        List::new(&((0..cs.len()).map(v).collect(): Vec<Value>)).into()
+    }
+}
+
+pure_function!(
+    REGEX_MATCH,
+    Signature::new(
+        vec!["string".into(), "pattern".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    regex_match
+);
+
+async fn regex_match(vs: Vec<Value>) -> Result<Value> {
+    let (s, r) = await!(arguments(vs[0].clone(), vs[1].clone()))?;
+    Ok(r.is_match(&s).into())
+}
+
+pure_function!(
+    REGEX_FIND,
+    Signature::new(
+        vec!["string".into(), "pattern".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    regex_find
+);
+
+async fn regex_find(vs: Vec<Value>) -> Result<Value> {
+    let (s, r) = await!(arguments(vs[0].clone(), vs[1].clone()))?;
+
+    Ok(match r.captures(&s) {
+        Some(cs) => captures(&r, cs),
+        None => Value::Nil,
+    })
+}
+
+pure_function!(
+    REGEX_REPLACE,
+    Signature::new(
+        vec!["string".into(), "pattern".into(), "replacement".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    regex_replace
+);
+
+async fn regex_replace(vs: Vec<Value>) -> Result<Value> {
+    let (s, r) = await!(arguments(vs[0].clone(), vs[1].clone()))?;
+    let t: String = await!(vs[2].clone().string())?.try_into()?;
+
+    Ok(r.replace_all(&s, t.as_str()).into_owned().into())
+}
+
+pure_function!(
+    REGEX_SPLIT,
+    Signature::new(
+        vec!["string".into(), "pattern".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    regex_split
+);
+
+async fn regex_split(vs: Vec<Value>) -> Result<Value> {
+    let (s, r) = await!(arguments(vs[0].clone(), vs[1].clone()))?;
+    Ok(List::new(&(r.split(&s).map(|s| s.into()).collect(): Vec<Value>)).into())
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::utils::papp;
+
+    fn strings(ss: &[&str]) -> Value {
+        List::new(&(ss.iter().map(|s| (*s).into()).collect(): Vec<Value>)).into()
+    }
+
+    #[test]
+    fn compile() {
+        let r = super::compile("[0-9]+").unwrap();
+
+        assert_eq!(r.as_str(), "[0-9]+");
+        assert_eq!(super::compile("[0-9]+").unwrap().as_str(), r.as_str());
+        assert!(CACHE.lock().unwrap().contains_key("[0-9]+"));
+    }
+
+    #[test]
+    fn compile_error() {
+        for (p, m) in vec![
+            ("a(b", "position 2"),
+            ("日本(", "position 3"),
+            ("a{2,1}", "position 2"),
+            ("\\p{Foo}", "position 1"),
+        ] {
+            let e = super::compile(p).unwrap_err();
+
+            assert_eq!(e.name(), "ValueError");
+            assert!(e.message().contains(m), "{}", e.message());
+        }
+    }
+
+    #[test]
+    fn functions() {
+        let d = Dictionary::new()
+            .strict_insert("key", "foo")
+            .strict_insert("value", "42");
+
+        for (f, vs, x) in vec![
+            (REGEX_MATCH.clone(), vec!["foo42".into(), "[0-9]+".into()], true.into()),
+            (REGEX_MATCH.clone(), vec!["foo".into(), "^[0-9]+$".into()], false.into()),
+            (REGEX_MATCH.clone(), vec!["日本語".into(), "^\\w{3}$".into()], true.into()),
+            (
+                REGEX_FIND.clone(),
+                vec!["a=1 b=2".into(), "(\\w)=(\\d)".into()],
+                strings(&["a=1", "a", "1"]),
+            ),
+            (
+                REGEX_FIND.clone(),
+                vec!["foo: 42".into(), "(?P<key>\\w+): (?P<value>\\d+)".into()],
+                d.into(),
+            ),
+            (
+                REGEX_FIND.clone(),
+                vec!["ab".into(), "a(x)?".into()],
+                List::new(&["a".into(), Value::Nil]).into(),
+            ),
+            (REGEX_FIND.clone(), vec!["foo".into(), "\\d".into()], Value::Nil),
+            (
+                REGEX_REPLACE.clone(),
+                vec!["a=1 b=2".into(), "(\\w)=(\\d)".into(), "$2=$1".into()],
+                "1=a 2=b".into(),
+            ),
+            (
+                REGEX_REPLACE.clone(),
+                vec!["héllo".into(), "[éo]".into(), "_".into()],
+                "h_ll_".into(),
+            ),
+            (
+                REGEX_SPLIT.clone(),
+                vec!["a, b,c".into(), ",\\s*".into()],
+                strings(&["a", "b", "c"]),
+            ),
+            (REGEX_SPLIT.clone(), vec!["".into(), ",".into()], strings(&[""])),
+        ]: Vec<(Value, Vec<Value>, Value)>
+        {
+            assert!(block_on_stable(papp(f, &vs).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn functions_error() {
+        for (f, vs) in vec![
+            (REGEX_MATCH.clone(), vec!["foo".into(), "(".into()]),
+            (REGEX_FIND.clone(), vec![42.into(), "foo".into()]),
+            (REGEX_SPLIT.clone(), vec!["foo".into(), 42.into()]),
+        ]: Vec<(Value, Vec<Value>)>
+        {
+            assert!(block_on_stable(papp(f, &vs).pured()).is_err());
+        }
+    }
+}
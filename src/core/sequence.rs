@@ -0,0 +1,232 @@
+use super::arguments::{Arguments, Expansion};
+use super::error::Error;
+use super::list::{List, CONCAT};
+use super::normal::Normal;
+use super::result::Result;
+use super::signature::Signature;
+use super::utils::{app, papp};
+use super::value::Value;
+
+pure_function!(
+    RANGE,
+    Signature::new(vec!["start".into()], "stopAndStep".into(), vec![], "".into()),
+    range
+);
+
+async fn range(vs: Vec<Value>) -> Result<Value> {
+    let n = await!(vs[0].clone().number())?;
+    let mut l = await!(vs[1].clone().list())?;
+
+    let mut m = Value::Nil;
+    let mut d = 1.0;
+
+    if !l.is_empty() {
+        m = l.first()?;
+        l = await!(l.rest())?;
+    }
+
+    if !l.is_empty() {
+        d = await!(l.first()?.number())?;
+        l = await!(l.rest())?;
+    }
+
+    if !l.is_empty() {
+        return Err(Error::argument("too many arguments to range"));
+    } else if d == 0.0 {
+        return Err(Error::value("step of range is zero"));
+    }
+
+    match await!(m.clone().pured())? {
+        Normal::Nil | Normal::Number(_) => {}
+        m => return Err(await!(Error::not_number(m.into()))?),
+    }
+
+    await!(range_from(vec![n.into(), 0.into(), m, d.into()]))
+}
+
+pure_function!(
+    RANGE_FROM,
+    Signature::new(
+        vec!["start".into(), "index".into(), "stop".into(), "step".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    range_from
+);
+
+// Elements are computed from the start and an index so that float errors do not accumulate.
+async fn range_from(vs: Vec<Value>) -> Result<Value> {
+    let n = await!(vs[0].clone().number())?;
+    let i = await!(vs[1].clone().number())?;
+    let m = vs[2].clone();
+    let d = await!(vs[3].clone().number())?;
+
+    let x = n + i * d;
+
+    if let Normal::Number(m) = await!(m.clone().pured())? {
+        if d > 0.0 && x > m || d < 0.0 && x < m {
+            return Ok(List::Empty.into());
+        }
+    }
+
+    Ok(List::cons(x, papp(RANGE_FROM.clone(), &[n.into(), (i + 1.0).into(), m, d.into()])).into())
+}
+
+pure_function!(
+    ITERATE,
+    Signature::new(
+        vec!["function".into(), "initial".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    iterate
+);
+
+async fn iterate(vs: Vec<Value>) -> Result<Value> {
+    let f = vs[0].clone();
+    let x = vs[1].clone();
+
+    Ok(List::cons(x.clone(), papp(ITERATE.clone(), &[f.clone(), papp(f, &[x])])).into())
+}
+
+pure_function!(
+    REPEAT,
+    Signature::new(vec!["x".into()], "".into(), vec![], "".into()),
+    repeat
+);
+
+async fn repeat(vs: Vec<Value>) -> Result<Value> {
+    let x = vs[0].clone();
+    Ok(List::cons(x.clone(), papp(REPEAT.clone(), &[x])).into())
+}
+
+pure_function!(
+    CYCLE,
+    Signature::new(vec!["list".into()], "".into(), vec![], "".into()),
+    cycle
+);
+
+async fn cycle(vs: Vec<Value>) -> Result<Value> {
+    let l = vs[0].clone();
+
+    if await!(l.clone().list())?.is_empty() {
+        return Ok(List::Empty.into());
+    }
+
+    Ok(app(
+        CONCAT.clone(),
+        Arguments::new(
+            &[Expansion::Expanded(
+                List::cons(l.clone(), papp(CYCLE.clone(), &[l])).into(),
+            )],
+            &[],
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::function::Function;
+    use super::super::list::TAKE;
+    use super::super::number::MULTIPLY;
+
+    fn take(n: usize, l: Value) -> Value {
+        papp(TAKE.clone(), &[n.into(), l])
+    }
+
+    #[test]
+    fn range() {
+        for (vs, x) in vec![
+            (vec![1.into()], vec![1.0, 2.0, 3.0]),
+            (vec![1.into(), 3.into()], vec![1.0, 2.0, 3.0]),
+            (vec![1.into(), 2.into()], vec![1.0, 2.0]),
+            (vec![1.into(), 1.into()], vec![1.0]),
+            (vec![1.into(), 0.into()], vec![]),
+            (vec![1.into(), Value::Nil, 2.into()], vec![1.0, 3.0, 5.0]),
+            (vec![1.into(), 6.into(), 2.into()], vec![1.0, 3.0, 5.0]),
+            (vec![3.into(), 1.into(), (-1.0).into()], vec![3.0, 2.0, 1.0]),
+            (vec![0.into(), 1.into(), 0.5.into()], vec![0.0, 0.5, 1.0]),
+        ]: Vec<(Vec<Value>, Vec<f64>)>
+        {
+            let x: Vec<Value> = x.into_iter().map(Value::from).collect();
+
+            assert!(
+                block_on_stable(take(3, papp(RANGE.clone(), &vs)).equal(List::new(&x).into()))
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn range_fractional_step() {
+        for (n, m, d, l) in vec![(0.0, 1.0, 0.1, 11), (0.0, 1.0, 0.05, 21), (1.0, 0.0, -0.1, 11)] {
+            let x: Vec<Value> = (0..l).map(|i| (n + i as f64 * d).into()).collect();
+
+            assert!(
+                block_on_stable(
+                    papp(RANGE.clone(), &[n.into(), m.into(), d.into()])
+                        .equal(List::new(&x).into())
+                ).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn range_error() {
+        for vs in vec![
+            vec![],
+            vec!["foo".into()],
+            vec![1.into(), "foo".into()],
+            vec![1.into(), 2.into(), 0.into()],
+            vec![1.into(), 2.into(), 3.into(), 4.into()],
+        ]: Vec<Vec<Value>>
+        {
+            assert!(block_on_stable(papp(RANGE.clone(), &vs).pured()).is_err());
+        }
+    }
+
+    #[test]
+    fn iterate() {
+        let f = Function::closure(MULTIPLY.clone(), Arguments::positionals(&[2.into()]));
+
+        assert!(
+            block_on_stable(
+                take(4, papp(ITERATE.clone(), &[f.into(), 1.into()]))
+                    .equal(List::new(&[1.into(), 2.into(), 4.into(), 8.into()]).into())
+            ).unwrap()
+        );
+    }
+
+    #[test]
+    fn repeat() {
+        assert!(
+            block_on_stable(
+                take(3, papp(REPEAT.clone(), &["foo".into()]))
+                    .equal(List::new(&["foo".into(), "foo".into(), "foo".into()]).into())
+            ).unwrap()
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        for (l, x) in vec![
+            (List::Empty, List::Empty),
+            (List::new(&[1.into()]), List::new(&[1.into(), 1.into(), 1.into()])),
+            (
+                List::new(&[1.into(), 2.into()]),
+                List::new(&[1.into(), 2.into(), 1.into()]),
+            ),
+        ] {
+            assert!(
+                block_on_stable(take(3, papp(CYCLE.clone(), &[l.into()])).equal(x.into()))
+                    .unwrap()
+            );
+        }
+    }
+}
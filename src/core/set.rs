@@ -0,0 +1,371 @@
+use std::sync::Arc;
+
+use futures::prelude::*;
+use hamt_sync::Map;
+
+use super::dictionary::Key;
+use super::error::Error;
+use super::list::List;
+use super::normal::Normal;
+use super::result::Result;
+use super::signature::Signature;
+use super::value::Value;
+
+#[derive(Clone, Debug)]
+pub struct Set(Arc<Map<Key, ()>>);
+
+impl Set {
+    pub fn new() -> Self {
+        Set(Arc::new(Map::new()))
+    }
+
+    pub fn size(&self) -> usize {
+        self.0.size()
+    }
+
+    pub fn insert(self, v: Value) -> Result<Set> {
+        let k = await!(Key::from_value(v))?;
+        Ok(self.strict_insert(k))
+    }
+
+    pub fn strict_insert(self, k: impl Into<Key>) -> Set {
+        Set::from(self.0.insert(k.into(), ()))
+    }
+
+    pub fn delete(self, v: Value) -> Result<Set> {
+        let k = await!(Key::from_value(v))?;
+
+        match self.0.delete(&k) {
+            Some(m) => Ok(m.into()),
+            None => {
+                let n: Normal = k.into();
+                Err(await!(Error::key_not_found(n.into()))?)
+            }
+        }
+    }
+
+    pub fn contains(self, v: Value) -> Result<bool> {
+        let k = await!(Key::from_value(v))?;
+        Ok(self.has(&k))
+    }
+
+    fn has(&self, k: &Key) -> bool {
+        self.0.find(k).is_some()
+    }
+
+    fn keys(&self) -> Vec<Key> {
+        self.0.into_iter().map(|(k, _)| k.clone()).collect()
+    }
+
+    pub fn to_vec(&self) -> Vec<Value> {
+        let mut ks = self.keys();
+        ks.sort();
+
+        ks.into_iter()
+            .map(|k| {
+                let n: Normal = k.into();
+                n.into()
+            })
+            .collect()
+    }
+
+    pub fn union(&self, s: &Self) -> Self {
+        let (mut m, s) = if self.size() < s.size() {
+            ((*s.0).clone(), self)
+        } else {
+            ((*self.0).clone(), s)
+        };
+
+        for k in s.keys() {
+            m = m.insert(k, ());
+        }
+
+        m.into()
+    }
+
+    pub fn intersection(&self, s: &Self) -> Self {
+        let (s, t) = if self.size() < s.size() {
+            (self, s)
+        } else {
+            (s, self)
+        };
+
+        let mut m = Map::new();
+
+        for k in s.keys() {
+            if t.has(&k) {
+                m = m.insert(k, ());
+            }
+        }
+
+        m.into()
+    }
+
+    pub fn difference(&self, s: &Self) -> Self {
+        let mut m = (*self.0).clone();
+
+        for k in s.keys() {
+            if let Some(n) = m.delete(&k) {
+                m = n;
+            }
+        }
+
+        m.into()
+    }
+
+    pub fn is_subset(&self, s: &Self) -> bool {
+        self.size() <= s.size() && self.keys().iter().all(|k| s.has(k))
+    }
+
+    pub fn to_string(self) -> Result<String> {
+        let mut ss = vec!["setOf".to_string()];
+
+        for v in self.to_vec() {
+            ss.push(await!(v.to_string())?);
+        }
+
+        Ok(["(", &ss.join(" "), ")"].concat())
+    }
+
+    pub fn equal(self, s: Self) -> Result<bool> {
+        Ok(self.size() == s.size() && self.is_subset(&s))
+    }
+}
+
+impl From<Map<Key, ()>> for Set {
+    fn from(m: Map<Key, ()>) -> Self {
+        Set(Arc::new(m))
+    }
+}
+
+fn sets(v: Value) -> Result<Vec<Set>> {
+    let mut l = await!(v.list())?;
+    let mut ss = vec![];
+
+    while !l.is_empty() {
+        ss.push(await!(l.first()?.set())?);
+        l = await!(l.rest())?;
+    }
+
+    Ok(ss)
+}
+
+pure_function!(
+    SET_OF,
+    Signature::new(vec![], "elements".into(), vec![], "".into()),
+    set_of
+);
+
+async fn set_of(vs: Vec<Value>) -> Result<Value> {
+    let mut s = Set::new();
+
+    for v in await!(await!(vs[0].clone().list())?.to_vec())? {
+        s = await!(s.insert(v))?;
+    }
+
+    Ok(s.into())
+}
+
+pure_function!(
+    TO_SET,
+    Signature::new(vec!["collection".into()], "".into(), vec![], "".into()),
+    to_set
+);
+
+async fn to_set(vs: Vec<Value>) -> Result<Value> {
+    let vs = match await!(vs[0].clone().pured())? {
+        Normal::List(l) => await!(l.to_vec())?,
+        Normal::Set(s) => return Ok(s.into()),
+        Normal::Vector(v) => v.to_vec(),
+        n => return Err(await!(Error::not_collection(n.into()))?),
+    };
+
+    Ok(await!(set_of(vec![List::new(&vs).into()]))?)
+}
+
+pure_function!(
+    UNION,
+    Signature::new(vec!["set".into()], "sets".into(), vec![], "".into()),
+    union
+);
+
+async fn union(vs: Vec<Value>) -> Result<Value> {
+    let mut s = await!(vs[0].clone().set())?;
+
+    for t in await!(sets(vs[1].clone()))? {
+        s = s.union(&t);
+    }
+
+    Ok(s.into())
+}
+
+pure_function!(
+    INTERSECTION,
+    Signature::new(vec!["set".into()], "sets".into(), vec![], "".into()),
+    intersection
+);
+
+async fn intersection(vs: Vec<Value>) -> Result<Value> {
+    let mut s = await!(vs[0].clone().set())?;
+
+    for t in await!(sets(vs[1].clone()))? {
+        s = s.intersection(&t);
+    }
+
+    Ok(s.into())
+}
+
+pure_function!(
+    DIFFERENCE,
+    Signature::new(vec!["set".into()], "sets".into(), vec![], "".into()),
+    difference
+);
+
+async fn difference(vs: Vec<Value>) -> Result<Value> {
+    let mut s = await!(vs[0].clone().set())?;
+
+    for t in await!(sets(vs[1].clone()))? {
+        s = s.difference(&t);
+    }
+
+    Ok(s.into())
+}
+
+pure_function!(
+    IS_SUBSET,
+    Signature::new(
+        vec!["set".into(), "superset".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    is_subset
+);
+
+async fn is_subset(vs: Vec<Value>) -> Result<Value> {
+    let s = await!(vs[0].clone().set())?;
+    let t = await!(vs[1].clone().set())?;
+
+    Ok(s.is_subset(&t).into())
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::utils::papp;
+
+    fn set(ks: &[f64]) -> Set {
+        ks.iter().fold(Set::new(), |s, k| s.strict_insert(*k))
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(Set::new().size(), 0);
+    }
+
+    #[test]
+    fn insert() {
+        let s = block_on_stable(Set::new().insert(1.into())).unwrap();
+        let s = block_on_stable(s.insert(1.into())).unwrap();
+        let s = block_on_stable(s.insert("foo".into())).unwrap();
+
+        assert_eq!(s.size(), 2);
+        assert!(block_on_stable(s.clone().contains("foo".into())).unwrap());
+        assert!(!block_on_stable(s.contains(2.into())).unwrap());
+        assert!(block_on_stable(Set::new().insert(Set::new().into())).is_err());
+    }
+
+    #[test]
+    fn delete() {
+        let s = block_on_stable(set(&[1.0, 2.0]).delete(1.into())).unwrap();
+
+        assert!(block_on_stable(s.clone().equal(set(&[2.0]))).unwrap());
+        assert_eq!(
+            block_on_stable(s.delete(1.into())).unwrap_err().name(),
+            "KeyNotFoundError"
+        );
+    }
+
+    #[test]
+    fn operations() {
+        let s = set(&[1.0, 2.0, 3.0]);
+        let t = set(&[2.0, 3.0, 4.0]);
+
+        for (x, y) in vec![
+            (s.union(&t), set(&[1.0, 2.0, 3.0, 4.0])),
+            (s.union(&Set::new()), s.clone()),
+            (s.intersection(&t), set(&[2.0, 3.0])),
+            (s.intersection(&Set::new()), Set::new()),
+            (s.difference(&t), set(&[1.0])),
+            (t.difference(&s), set(&[4.0])),
+            (s.difference(&Set::new()), s.clone()),
+        ] {
+            assert!(block_on_stable(x.equal(y)).unwrap());
+        }
+    }
+
+    #[test]
+    fn is_subset() {
+        for (s, t, b) in vec![
+            (set(&[]), set(&[]), true),
+            (set(&[]), set(&[1.0]), true),
+            (set(&[1.0]), set(&[]), false),
+            (set(&[1.0, 2.0]), set(&[1.0, 2.0, 3.0]), true),
+            (set(&[1.0, 4.0]), set(&[1.0, 2.0, 3.0]), false),
+        ] {
+            assert_eq!(s.is_subset(&t), b);
+        }
+    }
+
+    #[test]
+    fn equal() {
+        for (s, t, b) in vec![
+            (set(&[]), set(&[]), true),
+            (set(&[1.0, 2.0]), set(&[2.0, 1.0]), true),
+            (set(&[1.0]), set(&[1.0, 2.0]), false),
+            (set(&[1.0]), set(&[2.0]), false),
+        ] {
+            assert_eq!(block_on_stable(s.equal(t)).unwrap(), b);
+        }
+    }
+
+    #[test]
+    fn to_string() {
+        for (s, x) in vec![
+            (Set::new(), "(setOf)"),
+            (set(&[42.0]), "(setOf 42)"),
+            (set(&[3.0, 1.0, 2.0]), "(setOf 1 2 3)"),
+        ] {
+            assert_eq!(block_on_stable(s.to_string()).unwrap(), x);
+        }
+    }
+
+    #[test]
+    fn functions() {
+        let s: Value = set(&[1.0, 2.0]).into();
+        let t: Value = set(&[2.0, 3.0]).into();
+
+        for (f, vs, x) in vec![
+            (SET_OF.clone(), vec![], set(&[])),
+            (SET_OF.clone(), vec![1.into(), 2.into(), 1.into()], set(&[1.0, 2.0])),
+            (
+                TO_SET.clone(),
+                vec![List::new(&[2.into(), 1.into(), 2.into()]).into()],
+                set(&[1.0, 2.0]),
+            ),
+            (UNION.clone(), vec![s.clone(), t.clone()], set(&[1.0, 2.0, 3.0])),
+            (UNION.clone(), vec![s.clone()], set(&[1.0, 2.0])),
+            (INTERSECTION.clone(), vec![s.clone(), t.clone()], set(&[2.0])),
+            (DIFFERENCE.clone(), vec![s.clone(), t.clone()], set(&[1.0])),
+        ]: Vec<(Value, Vec<Value>, Set)>
+        {
+            assert!(block_on_stable(papp(f, &vs).equal(x.into())).unwrap());
+        }
+
+        assert!(!block_on_stable(papp(IS_SUBSET.clone(), &[s.clone(), t]).boolean()).unwrap());
+        assert!(block_on_stable(papp(UNION.clone(), &[s, 42.into()]).pured()).is_err());
+    }
+}
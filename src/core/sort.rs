@@ -0,0 +1,282 @@
+use std::cmp::{min, Ordering};
+
+use super::error::Error;
+use super::list::List;
+use super::normal::Normal;
+use super::parameters::OptionalParameter;
+use super::result::Result;
+use super::signature::Signature;
+use super::utils::papp;
+use super::value::Value;
+
+#[derive(Clone, Debug)]
+enum Comparison {
+    Natural(bool),
+    Function(Value),
+}
+
+impl Comparison {
+    fn before(self, x: Value, y: Value) -> Result<bool> {
+        match self {
+            Comparison::Natural(r) => {
+                let o = await!(x.compare(y))?;
+                Ok(o == if r { Ordering::Greater } else { Ordering::Less })
+            }
+            Comparison::Function(f) => match await!(papp(f, &[x, y]).pured())? {
+                Normal::Boolean(b) => Ok(b),
+                Normal::Number(n) => Ok(n < 0.0),
+                n => Err(await!(Error::typ(n.into(), "boolean or number".into()))?),
+            },
+        }
+    }
+}
+
+fn merge_sort(mut kvs: Vec<(Value, Value)>, c: Comparison) -> Result<List> {
+    let n = kvs.len();
+    let mut w = 1;
+
+    while w < n {
+        let mut ys = Vec::with_capacity(n);
+        let mut i = 0;
+
+        while i < n {
+            let m = min(i + w, n);
+            let e = min(i + 2 * w, n);
+            let (mut j, mut k) = (i, m);
+
+            while j < m && k < e {
+                if await!(c.clone().before(kvs[k].0.clone(), kvs[j].0.clone()))? {
+                    ys.push(kvs[k].clone());
+                    k += 1;
+                } else {
+                    ys.push(kvs[j].clone());
+                    j += 1;
+                }
+            }
+
+            ys.extend_from_slice(&kvs[j..m]);
+            ys.extend_from_slice(&kvs[k..e]);
+
+            i = e;
+        }
+
+        kvs = ys;
+        w *= 2;
+    }
+
+    Ok(List::new(&(kvs.into_iter().map(|(_, v)| v).collect(): Vec<Value>)))
+}
+
+fn key_value_pairs(l: Value, f: Option<Value>) -> Result<Vec<(Value, Value)>> {
+    let mut l = await!(l.list())?;
+    let mut kvs = vec![];
+
+    while !l.is_empty() {
+        let v = l.first()?;
+
+        kvs.push((
+            match f {
+                Some(ref f) => papp(f.clone(), &[v.clone()]),
+                None => v.clone(),
+            },
+            v,
+        ));
+
+        l = await!(l.rest())?;
+    }
+
+    Ok(kvs)
+}
+
+pure_function!(
+    SORT,
+    Signature::new(
+        vec!["list".into()],
+        "".into(),
+        vec![
+            OptionalParameter::new("key", Value::Nil),
+            OptionalParameter::new("reverse", false),
+        ],
+        "".into()
+    ),
+    sort
+);
+
+async fn sort(vs: Vec<Value>) -> Result<Value> {
+    let f = match await!(vs[1].clone().pured())? {
+        Normal::Nil => None,
+        Normal::Function(f) => Some(f.into()),
+        n => return Err(await!(Error::not_function(n.into()))?),
+    };
+
+    let r = await!(vs[2].clone().boolean())?;
+    let kvs = await!(key_value_pairs(vs[0].clone(), f))?;
+
+    Ok(await!(merge_sort(kvs, Comparison::Natural(r)))?.into())
+}
+
+pure_function!(
+    SORT_BY,
+    Signature::new(
+        vec!["function".into(), "list".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    sort_by
+);
+
+async fn sort_by(vs: Vec<Value>) -> Result<Value> {
+    let kvs = await!(key_value_pairs(vs[1].clone(), None))?;
+    Ok(await!(merge_sort(kvs, Comparison::Function(vs[0].clone())))?.into())
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::arguments::{Arguments, Expansion, KeywordArgument};
+    use super::super::function::Function;
+    use super::super::list::FIRST;
+    use super::super::number::{MULTIPLY, SUBTRACT};
+    use super::super::utils::app;
+
+    fn numbers(ns: &[f64]) -> Value {
+        List::new(&(ns.iter().map(|n| Value::from(*n)).collect(): Vec<Value>)).into()
+    }
+
+    fn pairs(ps: &[(f64, &str)]) -> Value {
+        List::new(
+            &(ps.iter()
+                .map(|&(n, s)| List::new(&[n.into(), s.into()]).into())
+                .collect(): Vec<Value>),
+        ).into()
+    }
+
+    pure_function!(
+        LESS,
+        Signature::new(vec!["x".into(), "y".into()], "".into(), vec![], "".into()),
+        less
+    );
+
+    async fn less(vs: Vec<Value>) -> Result<Value> {
+        Ok((await!(vs[0].clone().compare(vs[1].clone()))? == Ordering::Less).into())
+    }
+
+    fn sort_with(l: Value, ks: &[KeywordArgument]) -> Value {
+        let ks: Vec<Expansion<KeywordArgument>> =
+            ks.iter().map(|k| Expansion::Unexpanded(k.clone())).collect();
+
+        app(SORT.clone(), Arguments::new(&[Expansion::Unexpanded(l)], &ks))
+    }
+
+    #[test]
+    fn sort() {
+        for (l, x) in vec![
+            (numbers(&[]), numbers(&[])),
+            (numbers(&[1.0]), numbers(&[1.0])),
+            (numbers(&[2.0, 1.0]), numbers(&[1.0, 2.0])),
+            (
+                numbers(&[3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0]),
+                numbers(&[1.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 9.0]),
+            ),
+            (
+                List::new(&["b".into(), "c".into(), "a".into()]).into(),
+                List::new(&["a".into(), "b".into(), "c".into()]).into(),
+            ),
+            (
+                List::new(&[numbers(&[1.0, 2.0]), numbers(&[1.0]), numbers(&[0.0])]).into(),
+                List::new(&[numbers(&[0.0]), numbers(&[1.0]), numbers(&[1.0, 2.0])]).into(),
+            ),
+        ]: Vec<(Value, Value)>
+        {
+            assert!(block_on_stable(sort_with(l, &[]).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn sort_with_options() {
+        let negate: Value =
+            Function::closure(MULTIPLY.clone(), Arguments::positionals(&[(-1.0).into()])).into();
+
+        for (l, ks, x) in vec![
+            (
+                numbers(&[1.0, 3.0, 2.0]),
+                vec![KeywordArgument::new("key", negate)],
+                numbers(&[3.0, 2.0, 1.0]),
+            ),
+            (
+                numbers(&[1.0, 3.0, 2.0]),
+                vec![KeywordArgument::new("reverse", true)],
+                numbers(&[3.0, 2.0, 1.0]),
+            ),
+            (
+                pairs(&[(1.0, "a"), (0.0, "b"), (1.0, "c"), (0.0, "d")]),
+                vec![KeywordArgument::new("key", FIRST.clone())],
+                pairs(&[(0.0, "b"), (0.0, "d"), (1.0, "a"), (1.0, "c")]),
+            ),
+            (
+                pairs(&[(1.0, "a"), (0.0, "b"), (1.0, "c"), (0.0, "d")]),
+                vec![
+                    KeywordArgument::new("key", FIRST.clone()),
+                    KeywordArgument::new("reverse", true),
+                ],
+                pairs(&[(1.0, "a"), (1.0, "c"), (0.0, "b"), (0.0, "d")]),
+            ),
+        ]: Vec<(Value, Vec<KeywordArgument>, Value)>
+        {
+            assert!(block_on_stable(sort_with(l, &ks).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn sort_error() {
+        for l in vec![
+            List::new(&[1.into(), "a".into()]).into(),
+            List::new(&[true.into(), false.into()]).into(),
+            List::new(&[Value::Nil, Value::Nil]).into(),
+        ]: Vec<Value>
+        {
+            let e = block_on_stable(sort_with(l, &[]).pured()).unwrap_err();
+            assert_eq!(e.name(), "TypeError");
+        }
+    }
+
+    #[test]
+    fn sort_by() {
+        for (f, l, x) in vec![
+            (
+                SUBTRACT.clone(),
+                numbers(&[3.0, 1.0, 2.0]),
+                numbers(&[1.0, 2.0, 3.0]),
+            ),
+            (
+                LESS.clone(),
+                numbers(&[3.0, 1.0, 2.0]),
+                numbers(&[1.0, 2.0, 3.0]),
+            ),
+            (
+                Function::closure(SUBTRACT.clone(), Arguments::positionals(&[0.into()])).into(),
+                numbers(&[1.0]),
+                numbers(&[1.0]),
+            ),
+        ]: Vec<(Value, Value, Value)>
+        {
+            assert!(block_on_stable(papp(SORT_BY.clone(), &[f, l]).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn sort_by_error() {
+        let e = block_on_stable(
+            papp(
+                SORT_BY.clone(),
+                &[FIRST.clone(), numbers(&[1.0, 2.0])],
+            ).pured(),
+        ).unwrap_err();
+
+        assert_eq!(e.name(), "ArgumentError");
+    }
+}
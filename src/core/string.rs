@@ -1,81 +1,508 @@
+use std;
+use std::cmp::Ordering;
 use std::convert::TryInto;
 use std::fmt::{self, Debug, Formatter};
-use std::str::from_utf8;
+use std::hash::{Hash, Hasher};
+use std::str::from_utf8_unchecked;
 use std::sync::Arc;
 
+use futures::prelude::*;
+
 use super::error::Error;
+use super::list::List;
+use super::normal::Normal;
+use super::parameters::OptionalParameter;
+use super::result::Result;
+use super::signature::Signature;
+use super::value::Value;
+
+const INLINE_CAPACITY: usize = 14;
 
-// TODO: Optimize String by embedding small ones into struct without heap.
-#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Str(Arc<[u8]>);
+#[derive(Clone)]
+pub struct Str(Inner);
+
+#[derive(Clone)]
+enum Inner {
+    Inline(u8, [u8; INLINE_CAPACITY]),
+    // The length lives in the allocation so that Str fits into two words.
+    Heap(Arc<Box<str>>),
+}
 
 impl Str {
     pub fn merge(ss: &[Self]) -> Self {
-        (&(ss.iter().map(|s| &s.0 as &[u8]).collect(): Vec<&[u8]>).concat() as &[u8]).into()
+        (ss.iter().map(|s| s.as_str()).collect(): Vec<&str>).concat().into()
+    }
+
+    pub fn split(&self, i: usize) -> Option<(Self, Self)> {
+        let s = self.as_str();
+
+        let j = if i == self.len() {
+            s.len()
+        } else {
+            s.char_indices().nth(i)?.0
+        };
+
+        let (f, l) = s.split_at(j);
+        Some((f.into(), l.into()))
     }
 
-    pub fn split(&self, i: usize) -> (Self, Self) {
-        let (f, l) = self.0.split_at(i);
-        (f.into(), l.into())
+    pub fn len(&self) -> usize {
+        self.as_str().chars().count()
     }
 
-    fn as_slice(&self) -> &[u8] {
-        self.into()
+    pub fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self.0 {
+            // Inline bytes are always copied from a valid str.
+            Inner::Inline(n, ref bs) => unsafe { from_utf8_unchecked(&bs[..n as usize]) },
+            Inner::Heap(ref s) => s,
+        }
+    }
+
+    fn inline(s: &str) -> Option<Self> {
+        if s.len() > INLINE_CAPACITY {
+            return None;
+        }
+
+        let mut bs = [0; INLINE_CAPACITY];
+        bs[..s.len()].copy_from_slice(s.as_bytes());
+        Some(Str(Inner::Inline(s.len() as u8, bs)))
     }
 }
 
 impl Debug for Str {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{:?}", String::from_utf8_lossy(self.into()))
+        write!(f, "{:?}", self.as_str())
     }
 }
 
 impl Default for Str {
     fn default() -> Self {
-        Str((&[] as &[u8]).into())
+        "".into()
+    }
+}
+
+impl<'a> From<&'a str> for Str {
+    fn from(s: &'a str) -> Self {
+        Str::inline(s).unwrap_or_else(|| Str(Inner::Heap(Arc::new(s.into()))))
     }
 }
 
-impl<'a> Into<&'a [u8]> for &'a Str {
-    fn into(self) -> &'a [u8] {
-        &self.0
+impl From<String> for Str {
+    fn from(s: String) -> Self {
+        Str::inline(&s).unwrap_or_else(|| Str(Inner::Heap(Arc::new(s.into()))))
     }
 }
 
-impl<'a> From<&'a [u8]> for Str {
-    fn from(bs: &'a [u8]) -> Self {
-        Str(bs.into())
+impl Eq for Str {}
+
+impl Hash for Str {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
     }
 }
 
-impl<'a> From<&'a str> for Str {
-    fn from(s: &'a str) -> Self {
-        s.as_bytes().into()
+impl Ord for Str {
+    fn cmp(&self, s: &Self) -> Ordering {
+        self.as_str().cmp(s.as_str())
     }
 }
 
-impl From<String> for Str {
-    fn from(s: String) -> Self {
-        s.as_bytes().into()
+impl PartialEq for Str {
+    fn eq(&self, s: &Self) -> bool {
+        self.as_str() == s.as_str()
+    }
+}
+
+impl PartialOrd for Str {
+    fn partial_cmp(&self, s: &Self) -> Option<Ordering> {
+        Some(self.cmp(s))
     }
 }
 
 impl TryInto<String> for Str {
     type Error = Error;
 
-    fn try_into(self) -> Result<String, Self::Error> {
-        Ok(from_utf8(self.as_slice())?.into())
+    fn try_into(self) -> std::result::Result<String, Self::Error> {
+        Ok(self.as_str().into())
     }
 }
 
 impl<'a> PartialEq<&'a str> for Str {
     fn eq(&self, x: &&'a str) -> bool {
-        self.as_slice() == x.as_bytes()
+        self.as_str() == *x
     }
 }
 
 impl PartialEq<String> for Str {
     fn eq(&self, x: &String) -> bool {
-        self.as_slice() == x.as_bytes()
+        self.as_str() == x
+    }
+}
+
+pub fn characters(s: Str) -> Result<Vec<Value>> {
+    Ok(s.as_str().chars().map(|c| c.to_string().into()).collect())
+}
+
+fn string(v: Value) -> Result<String> {
+    Ok(await!(v.string())?.try_into()?)
+}
+
+pure_function!(
+    SPLIT,
+    Signature::new(
+        vec!["string".into()],
+        "".into(),
+        vec![OptionalParameter::new("separator", Value::Nil)],
+        "".into()
+    ),
+    split
+);
+
+async fn split(vs: Vec<Value>) -> Result<Value> {
+    let s = await!(string(vs[0].clone()))?;
+
+    let ss: Vec<Value> = match await!(vs[1].clone().pured())? {
+        Normal::Nil => s.split_whitespace().map(|s| s.into()).collect(),
+        Normal::String(t) => {
+            let t: String = t.try_into()?;
+
+            if t.is_empty() {
+                s.chars().map(|c| c.to_string().into()).collect()
+            } else {
+                s.split(t.as_str()).map(|s| s.into()).collect()
+            }
+        }
+        n => return Err(await!(Error::not_string(n.into()))?),
+    };
+
+    Ok(List::new(&ss).into())
+}
+
+pure_function!(
+    JOIN,
+    Signature::new(
+        vec!["strings".into()],
+        "".into(),
+        vec![OptionalParameter::new("separator", "")],
+        "".into()
+    ),
+    join
+);
+
+async fn join(vs: Vec<Value>) -> Result<Value> {
+    let mut l = await!(vs[0].clone().list())?;
+    let t = await!(string(vs[1].clone()))?;
+    let mut ss = vec![];
+
+    while !l.is_empty() {
+        ss.push(await!(string(l.first()?))?);
+        l = await!(l.rest())?;
+    }
+
+    Ok(ss.join(&t).into())
+}
+
+pure_function!(
+    TRIM,
+    Signature::new(vec!["string".into()], "".into(), vec![], "".into()),
+    trim
+);
+
+async fn trim(vs: Vec<Value>) -> Result<Value> {
+    Ok(await!(string(vs[0].clone()))?.trim().into())
+}
+
+pure_function!(
+    REPLACE,
+    Signature::new(
+        vec!["string".into(), "pattern".into(), "replacement".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    replace
+);
+
+async fn replace(vs: Vec<Value>) -> Result<Value> {
+    let s = await!(string(vs[0].clone()))?;
+    let p = await!(string(vs[1].clone()))?;
+    let r = await!(string(vs[2].clone()))?;
+
+    if p.is_empty() {
+        return Err(Error::value("pattern is empty"));
+    }
+
+    Ok(s.replace(p.as_str(), &r).into())
+}
+
+pure_function!(
+    STARTS_WITH,
+    Signature::new(
+        vec!["string".into(), "prefix".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    starts_with
+);
+
+async fn starts_with(vs: Vec<Value>) -> Result<Value> {
+    let s = await!(string(vs[0].clone()))?;
+    let p = await!(string(vs[1].clone()))?;
+
+    Ok(s.starts_with(p.as_str()).into())
+}
+
+pure_function!(
+    ENDS_WITH,
+    Signature::new(
+        vec!["string".into(), "suffix".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    ends_with
+);
+
+async fn ends_with(vs: Vec<Value>) -> Result<Value> {
+    let s = await!(string(vs[0].clone()))?;
+    let p = await!(string(vs[1].clone()))?;
+
+    Ok(s.ends_with(p.as_str()).into())
+}
+
+pure_function!(
+    UPPER,
+    Signature::new(vec!["string".into()], "".into(), vec![], "".into()),
+    upper
+);
+
+async fn upper(vs: Vec<Value>) -> Result<Value> {
+    Ok(await!(string(vs[0].clone()))?.to_uppercase().into())
+}
+
+pure_function!(
+    LOWER,
+    Signature::new(vec!["string".into()], "".into(), vec![], "".into()),
+    lower
+);
+
+async fn lower(vs: Vec<Value>) -> Result<Value> {
+    Ok(await!(string(vs[0].clone()))?.to_lowercase().into())
+}
+
+pure_function!(
+    CHARS,
+    Signature::new(vec!["string".into()], "".into(), vec![], "".into()),
+    chars
+);
+
+async fn chars(vs: Vec<Value>) -> Result<Value> {
+    Ok(List::new(&characters(await!(vs[0].clone().string())?)?).into())
+}
+
+pure_function!(
+    INDEX_OF,
+    Signature::new(
+        vec!["string".into(), "pattern".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    index_of
+);
+
+async fn index_of(vs: Vec<Value>) -> Result<Value> {
+    let s = await!(string(vs[0].clone()))?;
+    let p = await!(string(vs[1].clone()))?;
+
+    Ok(match s.find(p.as_str()) {
+        Some(i) => (s[..i].chars().count() + 1).into(),
+        None => Value::Nil,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::hash_map::DefaultHasher;
+    use std::mem::size_of;
+
+    use futures::stable::block_on_stable;
+    use test::{black_box, Bencher};
+
+    use super::*;
+
+    use super::super::arguments::{Arguments, Expansion, KeywordArgument};
+    use super::super::utils::{app, papp};
+
+    fn strings(ss: &[&str]) -> Value {
+        List::new(&(ss.iter().map(|s| (*s).into()).collect(): Vec<Value>)).into()
+    }
+
+    fn with_separator(f: Value, v: Value, s: &str) -> Value {
+        app(
+            f,
+            Arguments::new(
+                &[Expansion::Unexpanded(v)],
+                &[Expansion::Unexpanded(KeywordArgument::new("separator", s))],
+            ),
+        )
+    }
+
+    #[test]
+    fn size() {
+        let s = size_of::<Str>();
+        assert!(s <= 2 * size_of::<usize>(), "size of Str: {}", s);
+    }
+
+    #[test]
+    fn inline() {
+        for (s, b) in vec![
+            ("", true),
+            ("foo", true),
+            ("0123456789abcd", true),
+            ("0123456789abcde", false),
+            ("日本語日本", false),
+            ("日本語日", true),
+        ] {
+            let s = Str::from(s);
+
+            match s.0 {
+                Inner::Inline(_, _) => assert!(b),
+                Inner::Heap(_) => assert!(!b),
+            }
+        }
+    }
+
+    #[test]
+    fn clone_heap() {
+        let x = "a string too long to be inlined";
+        let s = Str::from(x);
+        let t = s.clone();
+
+        drop(s);
+        assert_eq!(t, x);
+        assert_eq!(t.clone(), x);
+    }
+
+    fn hash(s: &Str) -> u64 {
+        let mut h = DefaultHasher::new();
+        s.hash(&mut h);
+        h.finish()
+    }
+
+    #[test]
+    fn equal() {
+        let s = "0123456789abcdef";
+        let (f, l) = Str::from(s).split(14).unwrap();
+
+        assert_eq!(Str::merge(&[f.clone(), l]), Str::from(s));
+        assert_eq!(f, Str::from(String::from("0123456789abcd")));
+        assert_eq!(hash(&Str::merge(&[f, "ef".into()])), hash(&s.into()));
+        assert!(Str::from("0123456789abcd") < Str::from(s));
+    }
+
+    #[test]
+    fn merge() {
+        assert_eq!(Str::merge(&["foo".into(), "bar".into()]), "foobar");
+        assert_eq!(Str::merge(&["日本".into(), "語".into()]), "日本語");
+    }
+
+    #[test]
+    fn split() {
+        let s: Str = "héllo".into();
+
+        for (i, x, y) in vec![
+            (0, "", "héllo"),
+            (1, "h", "éllo"),
+            (2, "hé", "llo"),
+            (5, "héllo", ""),
+        ] {
+            let (f, l) = s.split(i).unwrap();
+
+            assert_eq!(f, x);
+            assert_eq!(l, y);
+        }
+
+        assert!(s.split(6).is_none());
+    }
+
+    #[test]
+    fn len() {
+        for (s, n) in vec![("", 0), ("foo", 3), ("héllo", 5), ("日本語", 3), ("👍", 1)] {
+            assert_eq!(Str::from(s).len(), n);
+        }
+    }
+
+    #[test]
+    fn functions() {
+        for (f, vs, x) in vec![
+            (SPLIT.clone(), vec![" foo  bar\tbaz\n".into()], strings(&["foo", "bar", "baz"])),
+            (SPLIT.clone(), vec!["".into()], strings(&[])),
+            (JOIN.clone(), vec![strings(&["foo", "bar"])], "foobar".into()),
+            (JOIN.clone(), vec![strings(&[])], "".into()),
+            (TRIM.clone(), vec!["\t foo bar \n".into()], "foo bar".into()),
+            (REPLACE.clone(), vec!["abab".into(), "b".into(), "c".into()], "acac".into()),
+            (REPLACE.clone(), vec!["café".into(), "é".into(), "e".into()], "cafe".into()),
+            (STARTS_WITH.clone(), vec!["foobar".into(), "foo".into()], true.into()),
+            (STARTS_WITH.clone(), vec!["foobar".into(), "bar".into()], false.into()),
+            (ENDS_WITH.clone(), vec!["foobar".into(), "bar".into()], true.into()),
+            (ENDS_WITH.clone(), vec!["foobar".into(), "foo".into()], false.into()),
+            (UPPER.clone(), vec!["straße".into()], "STRASSE".into()),
+            (LOWER.clone(), vec!["ÀÉÎ".into()], "àéî".into()),
+            (CHARS.clone(), vec!["日本語".into()], strings(&["日", "本", "語"])),
+            (CHARS.clone(), vec!["".into()], strings(&[])),
+            (INDEX_OF.clone(), vec!["héllo".into(), "llo".into()], 3.into()),
+            (INDEX_OF.clone(), vec!["foo".into(), "".into()], 1.into()),
+            (INDEX_OF.clone(), vec!["foo".into(), "bar".into()], Value::Nil),
+        ]: Vec<(Value, Vec<Value>, Value)>
+        {
+            assert!(block_on_stable(papp(f, &vs).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn separator() {
+        for (f, v, s, x) in vec![
+            (SPLIT.clone(), "a,b,,c".into(), ",", strings(&["a", "b", "", "c"])),
+            (SPLIT.clone(), "α→β".into(), "→", strings(&["α", "β"])),
+            (SPLIT.clone(), "αβ".into(), "", strings(&["α", "β"])),
+            (JOIN.clone(), strings(&["a", "b", "c"]), ", ", "a, b, c".into()),
+        ]: Vec<(Value, Value, &str, Value)>
+        {
+            assert!(block_on_stable(with_separator(f, v, s).equal(x)).unwrap());
+        }
+    }
+
+    #[test]
+    fn functions_error() {
+        for (f, vs) in vec![
+            (TRIM.clone(), vec![42.into()]),
+            (JOIN.clone(), vec![List::new(&["foo".into(), 42.into()]).into()]),
+            (REPLACE.clone(), vec!["foo".into(), "".into(), "bar".into()]),
+        ]: Vec<(Value, Vec<Value>)>
+        {
+            assert!(block_on_stable(papp(f, &vs).pured()).is_err());
+        }
+    }
+
+    #[bench]
+    fn bench_str_from_inline(b: &mut Bencher) {
+        b.iter(|| Str::from(black_box("foo")));
+    }
+
+    #[bench]
+    fn bench_str_from_heap(b: &mut Bencher) {
+        b.iter(|| Str::from(black_box("a string too long to be inlined")));
+    }
+
+    #[bench]
+    fn bench_str_clone(b: &mut Bencher) {
+        let s = Str::from("foo");
+        b.iter(|| black_box(&s).clone());
     }
 }
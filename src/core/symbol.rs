@@ -0,0 +1,179 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ptr;
+use std::sync::RwLock;
+
+use super::string::Str;
+
+// Interned names are leaked and never freed, so symbols created at run time are bounded.
+const MAX_SYMBOLS: usize = 1 << 20;
+
+lazy_static! {
+    static ref TABLE: RwLock<HashMap<Str, Symbol>> = RwLock::new(HashMap::new());
+}
+
+#[derive(Clone, Copy)]
+pub struct Symbol(&'static Str);
+
+impl Symbol {
+    pub fn new(s: Str) -> Self {
+        if let Some(&y) = TABLE.read().unwrap().get(&s) {
+            return y;
+        }
+
+        *TABLE
+            .write()
+            .unwrap()
+            .entry(s.clone())
+            .or_insert_with(|| Symbol(Box::leak(Box::new(s))))
+    }
+
+    pub fn try_new(s: Str) -> Option<Self> {
+        {
+            let t = TABLE.read().unwrap();
+
+            if t.len() >= MAX_SYMBOLS {
+                return t.get(&s).cloned();
+            }
+        }
+
+        Some(Self::new(s))
+    }
+
+    pub fn name(&self) -> Str {
+        self.0.clone()
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.0.as_str()
+    }
+}
+
+pub fn is_name(s: &str) -> bool {
+    !s.is_empty()
+        && !s.starts_with('.')
+        && !s.starts_with(':')
+        && s.chars().all(|c| !"()[]{}\\\"$".contains(c) && !c.is_whitespace())
+}
+
+impl Debug for Symbol {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl Default for Symbol {
+    fn default() -> Self {
+        "".into()
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.0 as *const Str as usize).hash(state)
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, y: &Self) -> Ordering {
+        if self == y {
+            Ordering::Equal
+        } else {
+            self.as_str().cmp(y.as_str())
+        }
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, y: &Self) -> bool {
+        ptr::eq(self.0, y.0)
+    }
+}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, y: &Self) -> Option<Ordering> {
+        Some(self.cmp(y))
+    }
+}
+
+impl<'a> From<&'a str> for Symbol {
+    fn from(s: &'a str) -> Self {
+        Symbol::new(s.into())
+    }
+}
+
+impl From<Str> for Symbol {
+    fn from(s: Str) -> Self {
+        Symbol::new(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread::spawn;
+
+    use test::{black_box, Bencher};
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(Symbol::from("foo"), Symbol::from("foo"));
+        assert_eq!(Symbol::from("foo"), Symbol::from(Str::from("foo")));
+        assert_ne!(Symbol::from("foo"), Symbol::from("bar"));
+        assert_eq!(Symbol::from(""), Symbol::default());
+    }
+
+    #[test]
+    fn name() {
+        for s in vec!["", "foo", "日本語", "a name too long to be inlined"] {
+            assert_eq!(Symbol::from(s).name(), s);
+            assert_eq!(Symbol::from(s).as_str(), s);
+        }
+    }
+
+    #[test]
+    fn order() {
+        assert!(Symbol::from("bar") < Symbol::from("foo"));
+        assert!(Symbol::from("foo") > Symbol::from("bar"));
+        assert_eq!(Symbol::from("foo").cmp(&Symbol::from("foo")), Ordering::Equal);
+    }
+
+    #[test]
+    fn new_concurrently() {
+        let hs: Vec<_> = (0..8)
+            .map(|_| spawn(|| Symbol::from("concurrent")))
+            .collect();
+
+        for h in hs {
+            assert_eq!(h.join().unwrap(), Symbol::from("concurrent"));
+        }
+    }
+
+    #[test]
+    fn is_name() {
+        for s in vec!["foo", "foo-bar?", "+", "日本語", "a:b", "x1"] {
+            assert!(super::is_name(s), "{}", s);
+        }
+
+        for s in vec!["", ".foo", ":foo", "foo bar", "a(b", "a\"b", "$a", "a\\b"] {
+            assert!(!super::is_name(s), "{}", s);
+        }
+    }
+
+    #[bench]
+    fn bench_symbol_new(b: &mut Bencher) {
+        Symbol::from("foo");
+        b.iter(|| Symbol::from(black_box("foo")));
+    }
+
+    #[bench]
+    fn bench_symbol_cmp(b: &mut Bencher) {
+        let (x, y) = (Symbol::from("foo"), Symbol::from("bar"));
+        b.iter(|| black_box(x).cmp(&black_box(y)));
+    }
+}
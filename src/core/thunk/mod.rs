@@ -1,4 +1,5 @@
 mod black_hole;
 mod thunk;
 
+pub use self::black_hole::BlackHole;
 pub use self::thunk::Thunk;
@@ -0,0 +1,118 @@
+use super::normal::Normal;
+use super::result::Result;
+use super::signature::Signature;
+use super::value::Value;
+
+pure_function!(
+    TYPE_OF,
+    Signature::new(vec!["x".into()], "".into(), vec![], "".into()),
+    type_of
+);
+
+async fn type_of(vs: Vec<Value>) -> Result<Value> {
+    Ok(await!(vs[0].clone().type_name())?.into())
+}
+
+macro_rules! type_predicate {
+    ($i:ident, $f:ident, $p:pat) => {
+        pure_function!(
+            $i,
+            Signature::new(vec!["x".into()], "".into(), vec![], "".into()),
+            $f
+        );
+
+        async fn $f(vs: Vec<Value>) -> Result<Value> {
+            Ok(match await!(vs[0].clone().pured())? {
+                $p => true,
+                _ => false,
+            }.into())
+        }
+    };
+}
+
+type_predicate!(IS_BOOLEAN, is_boolean, Normal::Boolean(_));
+type_predicate!(IS_BYTES, is_bytes, Normal::Bytes(_));
+type_predicate!(IS_DICTIONARY, is_dictionary, Normal::Dictionary(_));
+type_predicate!(IS_FUNCTION, is_function, Normal::Function(_));
+type_predicate!(IS_LIST, is_list, Normal::List(_));
+type_predicate!(IS_NIL, is_nil, Normal::Nil);
+type_predicate!(IS_NUMBER, is_number, Normal::Number(_));
+type_predicate!(IS_SET, is_set, Normal::Set(_));
+type_predicate!(IS_STRING, is_string, Normal::String(_));
+type_predicate!(IS_SYMBOL, is_symbol, Normal::Symbol(_));
+type_predicate!(IS_VECTOR, is_vector, Normal::Vector(_));
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::bytes::Bytes;
+    use super::super::dictionary::Dictionary;
+    use super::super::list::{List, FIRST};
+    use super::super::set::Set;
+    use super::super::symbol::Symbol;
+    use super::super::utils::{papp, TEST_FUNCTION};
+    use super::super::vector::Vector;
+
+    #[test]
+    fn type_of() {
+        for (x, s) in vec![
+            (true.into(), "boolean"),
+            (Bytes::default().into(), "bytes"),
+            (Dictionary::new().into(), "dictionary"),
+            (TEST_FUNCTION.clone(), "function"),
+            (List::Empty.into(), "list"),
+            (Value::Nil, "nil"),
+            (42.into(), "number"),
+            (Set::new().into(), "set"),
+            ("foo".into(), "string"),
+            (Symbol::from("foo").into(), "symbol"),
+            (Vector::default().into(), "vector"),
+        ]: Vec<(Value, &str)>
+        {
+            assert!(block_on_stable(papp(TYPE_OF.clone(), &[x]).equal(s.into())).unwrap());
+        }
+    }
+
+    #[test]
+    fn type_predicates() {
+        for (f, x, b) in vec![
+            (IS_BOOLEAN.clone(), true.into(), true),
+            (IS_BOOLEAN.clone(), 42.into(), false),
+            (IS_BYTES.clone(), Bytes::default().into(), true),
+            (IS_BYTES.clone(), "".into(), false),
+            (IS_DICTIONARY.clone(), Dictionary::new().into(), true),
+            (IS_DICTIONARY.clone(), List::Empty.into(), false),
+            (IS_FUNCTION.clone(), TEST_FUNCTION.clone(), true),
+            (IS_FUNCTION.clone(), Value::Nil, false),
+            (IS_LIST.clone(), List::Empty.into(), true),
+            (IS_LIST.clone(), "foo".into(), false),
+            (IS_NIL.clone(), Value::Nil, true),
+            (IS_NIL.clone(), false.into(), false),
+            (IS_NUMBER.clone(), 42.into(), true),
+            (IS_NUMBER.clone(), "42".into(), false),
+            (IS_SET.clone(), Set::new().into(), true),
+            (IS_SET.clone(), Dictionary::new().into(), false),
+            (IS_STRING.clone(), "foo".into(), true),
+            (IS_STRING.clone(), 42.into(), false),
+            (IS_SYMBOL.clone(), Symbol::from("foo").into(), true),
+            (IS_SYMBOL.clone(), "foo".into(), false),
+            (IS_VECTOR.clone(), Vector::default().into(), true),
+            (IS_VECTOR.clone(), List::Empty.into(), false),
+        ]: Vec<(Value, Value, bool)>
+        {
+            assert_eq!(block_on_stable(papp(f, &[x]).boolean()).unwrap(), b);
+        }
+    }
+
+    #[test]
+    fn type_predicates_weak_head_normal_form() {
+        let e = papp(FIRST.clone(), &[List::Empty.into()]);
+
+        assert!(
+            block_on_stable(papp(IS_LIST.clone(), &[List::new(&[e]).into()]).boolean()).unwrap()
+        );
+    }
+}
@@ -2,6 +2,7 @@ use std;
 use std::cmp::Ordering;
 use std::convert::TryInto;
 
+use super::bytes::Bytes;
 use super::collection::{INSERT, MERGE};
 use super::dictionary::Dictionary;
 use super::error::Error;
@@ -9,20 +10,27 @@ use super::function::Function;
 use super::list::List;
 use super::normal::Normal;
 use super::result::Result;
+use super::set::Set;
 use super::string::Str;
+use super::symbol::Symbol;
 use super::thunk::Thunk;
 use super::utils::papp;
+use super::vector::Vector;
 
 #[derive(Clone, Debug)]
 pub enum Value {
     // from Normal
     Boolean(bool),
+    Bytes(Bytes),
     Dictionary(Dictionary),
     Function(Function),
     List(List),
     Nil,
     Number(f64),
+    Set(Set),
     String(Str),
+    Symbol(Symbol),
+    Vector(Vector),
 
     Thunk(Thunk),
 }
@@ -51,6 +59,15 @@ impl Value {
         }
     }
 
+    pub fn bytes(self) -> Result<Bytes> {
+        let n = await!(self.pured())?;
+
+        match n {
+            Normal::Bytes(bs) => Ok(bs),
+            _ => Err(await!(Error::not_bytes(n.into()))?),
+        }
+    }
+
     pub fn dictionary(self) -> Result<Dictionary> {
         let n = await!(self.pured())?;
 
@@ -88,6 +105,16 @@ impl Value {
         }
     }
 
+    pub fn natural(self) -> Result<usize> {
+        let n = await!(self.number())?;
+
+        if n % 1.0 == 0.0 && n >= 0.0 {
+            Ok(n as usize)
+        } else {
+            Err(Error::value(&format!("{} is not a natural number", n)))
+        }
+    }
+
     pub fn number(self) -> Result<f64> {
         let n = await!(self.pured())?;
 
@@ -97,6 +124,15 @@ impl Value {
         }
     }
 
+    pub fn set(self) -> Result<Set> {
+        let n = await!(self.pured())?;
+
+        match n {
+            Normal::Set(s) => Ok(s),
+            _ => Err(await!(Error::not_set(n.into()))?),
+        }
+    }
+
     pub fn string(self) -> Result<Str> {
         let n = await!(self.pured())?;
 
@@ -106,7 +142,25 @@ impl Value {
         }
     }
 
-    fn type_name(self) -> Result<Str> {
+    pub fn symbol(self) -> Result<Symbol> {
+        let n = await!(self.pured())?;
+
+        match n {
+            Normal::Symbol(s) => Ok(s),
+            _ => Err(await!(Error::not_symbol(n.into()))?),
+        }
+    }
+
+    pub fn vector(self) -> Result<Vector> {
+        let n = await!(self.pured())?;
+
+        match n {
+            Normal::Vector(v) => Ok(v),
+            _ => Err(await!(Error::not_vector(n.into()))?),
+        }
+    }
+
+    pub fn type_name(self) -> Result<Str> {
         Ok(await!(self.pured())?.type_name())
     }
 
@@ -142,12 +196,16 @@ impl<T: Into<Normal>> From<T> for Value {
     fn from(x: T) -> Self {
         match x.into() {
             Normal::Boolean(b) => Value::Boolean(b),
+            Normal::Bytes(bs) => Value::Bytes(bs),
             Normal::Dictionary(d) => Value::Dictionary(d),
             Normal::Function(f) => Value::Function(f),
             Normal::List(l) => Value::List(l),
             Normal::Nil => Value::Nil,
             Normal::Number(n) => Value::Number(n),
+            Normal::Set(s) => Value::Set(s),
             Normal::String(s) => Value::String(s),
+            Normal::Symbol(s) => Value::Symbol(s),
+            Normal::Vector(v) => Value::Vector(v),
         }
     }
 }
@@ -175,12 +233,16 @@ impl TryInto<Normal> for Value {
     fn try_into(self) -> std::result::Result<Normal, Self::Error> {
         match self {
             Value::Boolean(b) => Ok(b.into()),
+            Value::Bytes(bs) => Ok(bs.into()),
             Value::Dictionary(d) => Ok(d.into()),
             Value::Function(f) => Ok(f.into()),
             Value::List(l) => Ok(l.into()),
             Value::Nil => Ok(Normal::Nil),
             Value::Number(n) => Ok(n.into()),
+            Value::Set(s) => Ok(s.into()),
             Value::String(s) => Ok(s.into()),
+            Value::Symbol(s) => Ok(s.into()),
+            Value::Vector(v) => Ok(v.into()),
             _ => Err(Error::unreachable()),
         }
     }
@@ -227,6 +289,14 @@ mod test {
             (42.into(), "42"),
             (1.5.into(), "1.5"),
             ("foo".into(), "\"foo\""),
+            (Symbol::from("foo").into(), ":foo"),
+            (
+                Dictionary::new()
+                    .strict_insert(Symbol::from("foo"), 1)
+                    .strict_insert("foo", 2)
+                    .into(),
+                "{\"foo\" 2 :foo 1}",
+            ),
         ]: Vec<(Value, &str)>
         {
             assert_eq!(&block_on_stable(v.clone().to_string()).unwrap(), s);
@@ -0,0 +1,531 @@
+use std::cmp::{max, Ordering};
+use std::sync::Arc;
+
+use futures::prelude::*;
+
+use super::error::Error;
+use super::list::List;
+use super::normal::Normal;
+use super::result::Result;
+use super::signature::Signature;
+use super::value::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct Vector(Option<Arc<Node>>);
+
+#[derive(Debug)]
+struct Node {
+    left: Vector,
+    value: Value,
+    right: Vector,
+    size: usize,
+    height: usize,
+}
+
+impl Vector {
+    pub fn new(vs: &[Value]) -> Self {
+        if vs.is_empty() {
+            return Self::default();
+        }
+
+        let m = vs.len() / 2;
+
+        Self::node(Self::new(&vs[..m]), vs[m].clone(), Self::new(&vs[m + 1..]))
+    }
+
+    fn node(l: Self, v: Value, r: Self) -> Self {
+        Vector(Some(Arc::new(Node {
+            size: l.len() + r.len() + 1,
+            height: max(l.height(), r.height()) + 1,
+            left: l,
+            value: v,
+            right: r,
+        })))
+    }
+
+    fn expose(&self) -> (Self, Value, Self) {
+        match self.0 {
+            Some(ref n) => (n.left.clone(), n.value.clone(), n.right.clone()),
+            None => unreachable!(),
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self.0 {
+            Some(ref n) => n.height,
+            None => 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self.0 {
+            Some(ref n) => n.size,
+            None => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    pub fn get(&self, mut i: usize) -> Option<Value> {
+        let mut t = self;
+
+        while let Some(ref n) = t.0 {
+            let s = n.left.len();
+
+            if i < s {
+                t = &n.left;
+            } else if i == s {
+                return Some(n.value.clone());
+            } else {
+                i -= s + 1;
+                t = &n.right;
+            }
+        }
+
+        None
+    }
+
+    pub fn set(&self, i: usize, v: Value) -> Option<Self> {
+        let (l, x, r) = match self.0 {
+            Some(_) => self.expose(),
+            None => return None,
+        };
+
+        let s = l.len();
+
+        Some(if i < s {
+            Self::node(l.set(i, v)?, x, r)
+        } else if i == s {
+            Self::node(l, v, r)
+        } else {
+            Self::node(l, x, r.set(i - s - 1, v)?)
+        })
+    }
+
+    pub fn push(&self, v: Value) -> Self {
+        Self::join(self.clone(), v, Self::default())
+    }
+
+    pub fn insert(&self, i: usize, v: Value) -> Option<Self> {
+        if i > self.len() {
+            return None;
+        }
+
+        let (l, r) = self.split(i);
+        Some(Self::join(l, v, r))
+    }
+
+    pub fn delete(&self, i: usize) -> Option<Self> {
+        if i >= self.len() {
+            return None;
+        }
+
+        let (l, r) = self.split(i);
+        Some(l.merge(&r.split(1).1))
+    }
+
+    pub fn slice(&self, i: usize, j: usize) -> Option<Self> {
+        if i > j || j > self.len() {
+            return None;
+        }
+
+        Some(self.split(j).0.split(i).1)
+    }
+
+    pub fn merge(&self, v: &Self) -> Self {
+        match v.get(0) {
+            Some(x) => Self::join(self.clone(), x, v.split(1).1),
+            None => self.clone(),
+        }
+    }
+
+    fn split(&self, i: usize) -> (Self, Self) {
+        if self.is_empty() {
+            return (Self::default(), Self::default());
+        }
+
+        let (l, v, r) = self.expose();
+        let s = l.len();
+
+        if i <= s {
+            let (ll, lr) = l.split(i);
+            (ll, Self::join(lr, v, r))
+        } else {
+            let (rl, rr) = r.split(i - s - 1);
+            (Self::join(l, v, rl), rr)
+        }
+    }
+
+    fn join(l: Self, v: Value, r: Self) -> Self {
+        if l.height() > r.height() + 1 {
+            let (ll, lv, lr) = l.expose();
+            Self::balance(ll, lv, Self::join(lr, v, r))
+        } else if r.height() > l.height() + 1 {
+            let (rl, rv, rr) = r.expose();
+            Self::balance(Self::join(l, v, rl), rv, rr)
+        } else {
+            Self::node(l, v, r)
+        }
+    }
+
+    fn balance(l: Self, v: Value, r: Self) -> Self {
+        if l.height() > r.height() + 1 {
+            let (ll, lv, lr) = l.expose();
+
+            if ll.height() >= lr.height() {
+                Self::node(ll, lv, Self::node(lr, v, r))
+            } else {
+                let (lrl, lrv, lrr) = lr.expose();
+                Self::node(Self::node(ll, lv, lrl), lrv, Self::node(lrr, v, r))
+            }
+        } else if r.height() > l.height() + 1 {
+            let (rl, rv, rr) = r.expose();
+
+            if rr.height() >= rl.height() {
+                Self::node(Self::node(l, v, rl), rv, rr)
+            } else {
+                let (rll, rlv, rlr) = rl.expose();
+                Self::node(Self::node(l, v, rll), rlv, Self::node(rlr, rv, rr))
+            }
+        } else {
+            Self::node(l, v, r)
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<Value> {
+        let mut vs = Vec::with_capacity(self.len());
+        self.collect(&mut vs);
+        vs
+    }
+
+    fn collect(&self, vs: &mut Vec<Value>) {
+        if let Some(ref n) = self.0 {
+            n.left.collect(vs);
+            vs.push(n.value.clone());
+            n.right.collect(vs);
+        }
+    }
+
+    pub fn to_list(&self) -> List {
+        List::new(&self.to_vec())
+    }
+
+    pub fn to_string(self) -> Result<String> {
+        let mut ss = vec!["vector".to_string()];
+
+        for v in self.to_vec() {
+            ss.push(await!(v.to_string())?);
+        }
+
+        Ok(["(", &ss.join(" "), ")"].concat())
+    }
+
+    pub fn equal(self, v: Self) -> Result<bool> {
+        if self.len() != v.len() {
+            return Ok(false);
+        }
+
+        for (x, y) in self.to_vec().into_iter().zip(v.to_vec()) {
+            if !await!(x.equal(y))? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    pub fn compare(self, v: Self) -> Result<Ordering> {
+        for (x, y) in self.to_vec().into_iter().zip(v.to_vec()) {
+            let o = await!(x.compare(y))?;
+
+            if o != Ordering::Equal {
+                return Ok(o);
+            }
+        }
+
+        Ok(self.len().cmp(&v.len()))
+    }
+}
+
+pure_function!(
+    VECTOR,
+    Signature::new(vec![], "elements".into(), vec![], "".into()),
+    vector
+);
+
+async fn vector(vs: Vec<Value>) -> Result<Value> {
+    Ok(Vector::new(&await!(await!(vs[0].clone().list())?.to_vec())?).into())
+}
+
+pure_function!(
+    TO_VECTOR,
+    Signature::new(vec!["list".into()], "".into(), vec![], "".into()),
+    to_vector
+);
+
+async fn to_vector(vs: Vec<Value>) -> Result<Value> {
+    Ok(match await!(vs[0].clone().pured())? {
+        Normal::List(l) => Vector::new(&await!(l.to_vec())?).into(),
+        Normal::Vector(v) => v.into(),
+        n => return Err(await!(Error::not_list(n.into()))?),
+    })
+}
+
+pure_function!(
+    SET,
+    Signature::new(
+        vec!["vector".into(), "index".into(), "value".into()],
+        "".into(),
+        vec![],
+        "".into()
+    ),
+    set
+);
+
+async fn set(vs: Vec<Value>) -> Result<Value> {
+    let v = await!(vs[0].clone().vector())?;
+    let i = await!(vs[1].clone().index())?;
+
+    match v.set(i - 1, vs[2].clone()) {
+        Some(v) => Ok(v.into()),
+        None => Err(Error::index_out_of_range(i as i64)),
+    }
+}
+
+pure_function!(
+    PUSH,
+    Signature::new(vec!["vector".into()], "elements".into(), vec![], "".into()),
+    push
+);
+
+async fn push(vs: Vec<Value>) -> Result<Value> {
+    let mut v = await!(vs[0].clone().vector())?;
+
+    for x in await!(await!(vs[1].clone().list())?.to_vec())? {
+        v = v.push(x);
+    }
+
+    Ok(v.into())
+}
+
+#[cfg(test)]
+mod test {
+    use futures::stable::block_on_stable;
+
+    use super::*;
+
+    use super::super::utils::papp;
+
+    fn numbers(n: usize) -> Vec<Value> {
+        (0..n).map(Value::from).collect()
+    }
+
+    fn range(m: usize, n: usize) -> Vec<usize> {
+        (m..n).collect()
+    }
+
+    fn assert_numbers(v: &Vector, ns: &[usize]) {
+        assert_eq!(v.len(), ns.len());
+
+        for (x, n) in v.to_vec().into_iter().zip(ns) {
+            assert_eq!(block_on_stable(x.number()).unwrap(), *n as f64);
+        }
+    }
+
+    fn assert_balanced(v: &Vector) {
+        if let Some(ref n) = v.0 {
+            assert!(n.left.height() <= n.right.height() + 1);
+            assert!(n.right.height() <= n.left.height() + 1);
+            assert_eq!(n.size, n.left.len() + n.right.len() + 1);
+
+            assert_balanced(&n.left);
+            assert_balanced(&n.right);
+        }
+    }
+
+    #[test]
+    fn new() {
+        for n in 0..64 {
+            let v = Vector::new(&numbers(n));
+
+            assert_balanced(&v);
+            assert_numbers(&v, &range(0, n));
+        }
+    }
+
+    #[test]
+    fn get() {
+        let v = Vector::new(&numbers(100));
+
+        for i in 0..100 {
+            assert_eq!(block_on_stable(v.get(i).unwrap().number()).unwrap(), i as f64);
+        }
+
+        assert!(v.get(100).is_none());
+        assert!(Vector::default().get(0).is_none());
+    }
+
+    #[test]
+    fn set() {
+        let v = Vector::new(&numbers(3));
+        let w = v.set(1, 42.into()).unwrap();
+
+        assert_numbers(&v, &[0, 1, 2]);
+        assert_numbers(&w, &[0, 42, 2]);
+        assert!(v.set(3, 42.into()).is_none());
+    }
+
+    #[test]
+    fn push() {
+        let mut v = Vector::default();
+
+        for i in 0..1000 {
+            v = v.push(Value::from(i as usize));
+            assert_balanced(&v);
+        }
+
+        assert_numbers(&v, &range(0, 1000));
+    }
+
+    #[test]
+    fn insert() {
+        let v = Vector::new(&numbers(3));
+
+        assert_numbers(&v.insert(0, 42.into()).unwrap(), &[42, 0, 1, 2]);
+        assert_numbers(&v.insert(1, 42.into()).unwrap(), &[0, 42, 1, 2]);
+        assert_numbers(&v.insert(3, 42.into()).unwrap(), &[0, 1, 2, 42]);
+        assert!(v.insert(4, 42.into()).is_none());
+    }
+
+    #[test]
+    fn delete() {
+        let v = Vector::new(&numbers(3));
+
+        assert_numbers(&v.delete(0).unwrap(), &[1, 2]);
+        assert_numbers(&v.delete(1).unwrap(), &[0, 2]);
+        assert_numbers(&v.delete(2).unwrap(), &[0, 1]);
+        assert!(v.delete(3).is_none());
+
+        let mut v = Vector::new(&numbers(100));
+
+        for _ in 0..50 {
+            v = v.delete(v.len() / 2).unwrap();
+            assert_balanced(&v);
+        }
+
+        assert_eq!(v.len(), 50);
+    }
+
+    #[test]
+    fn slice() {
+        let v = Vector::new(&numbers(100));
+
+        for i in 0..100 {
+            for j in i..101 {
+                let w = v.slice(i, j).unwrap();
+
+                assert_balanced(&w);
+                assert_numbers(&w, &range(i, j));
+            }
+        }
+
+        assert!(v.slice(2, 1).is_none());
+        assert!(v.slice(0, 101).is_none());
+    }
+
+    #[test]
+    fn merge() {
+        for (m, n) in vec![(0, 0), (0, 1), (1, 0), (1, 1), (3, 100), (100, 3), (64, 64)] {
+            let v = Vector::new(&numbers(m));
+            let ws: Vec<Value> = range(m, m + n).into_iter().map(Value::from).collect();
+            let w = Vector::new(&ws);
+            let x = v.merge(&w);
+
+            assert_balanced(&x);
+            assert_numbers(&x, &range(0, m + n));
+        }
+    }
+
+    #[test]
+    fn to_string() {
+        for (v, s) in vec![
+            (Vector::default(), "(vector)"),
+            (Vector::new(&[42.into()]), "(vector 42)"),
+            (Vector::new(&[1.into(), "foo".into()]), "(vector 1 \"foo\")"),
+        ] {
+            assert_eq!(block_on_stable(v.to_string()).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn compare() {
+        for (v, w, o) in vec![
+            (vec![], vec![], Ordering::Equal),
+            (vec![], vec![0.into()], Ordering::Less),
+            (vec![1.into()], vec![0.into(), 1.into()], Ordering::Greater),
+            (vec![0.into(), 1.into()], vec![0.into(), 1.into()], Ordering::Equal),
+        ]: Vec<(Vec<Value>, Vec<Value>, Ordering)>
+        {
+            assert_eq!(
+                block_on_stable(Vector::new(&v).compare(Vector::new(&w))).unwrap(),
+                o
+            );
+        }
+    }
+
+    #[test]
+    fn vector() {
+        assert!(
+            block_on_stable(
+                papp(VECTOR.clone(), &[1.into(), 2.into()])
+                    .equal(Vector::new(&[1.into(), 2.into()]).into())
+            ).unwrap()
+        );
+    }
+
+    #[test]
+    fn to_vector() {
+        for v in vec![
+            List::new(&[1.into(), 2.into()]).into(),
+            Vector::new(&[1.into(), 2.into()]).into(),
+        ]: Vec<Value>
+        {
+            assert!(
+                block_on_stable(
+                    papp(TO_VECTOR.clone(), &[v]).equal(Vector::new(&[1.into(), 2.into()]).into())
+                ).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn set_function() {
+        let v: Value = Vector::new(&[1.into(), 2.into()]).into();
+
+        assert!(
+            block_on_stable(
+                papp(SET.clone(), &[v.clone(), 2.into(), 42.into()])
+                    .equal(Vector::new(&[1.into(), 42.into()]).into())
+            ).unwrap()
+        );
+
+        for i in vec![0, 3]: Vec<usize> {
+            assert!(block_on_stable(papp(SET.clone(), &[v.clone(), i.into(), 42.into()]).pured())
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn push_function() {
+        assert!(
+            block_on_stable(
+                papp(
+                    PUSH.clone(),
+                    &[Vector::new(&[1.into()]).into(), 2.into(), 3.into()]
+                ).equal(Vector::new(&[1.into(), 2.into(), 3.into()]).into())
+            ).unwrap()
+        );
+    }
+}
@@ -1,7 +1,251 @@
-use super::super::ast::Module;
+use super::super::ast::{
+    Arguments, Builtin, DefFunction, Effect, Expansion, Expression, HalfSignature, InnerStatement,
+    Interpolation, KeywordArgument, LetVariable, Module, OptionalParameter, Signature, Statement,
+};
 
 use super::error::DesugarError;
 
 pub fn desugar(m: Module) -> Result<Module, DesugarError> {
-    Ok(m)
+    Ok(Module::new(
+        m.pragmas,
+        m.imports,
+        m.statements.into_iter().map(statement).collect(),
+    ))
+}
+
+fn statement(s: Statement) -> Statement {
+    match s {
+        Statement::DefFunction(f) => Statement::DefFunction(def_function(f)),
+        Statement::Effect(e) => Statement::Effect(Effect::new(expression(e.value), e.expanded)),
+        Statement::LetVariable(l) => Statement::LetVariable(let_variable(l)),
+    }
+}
+
+fn inner_statement(s: InnerStatement) -> InnerStatement {
+    match s {
+        InnerStatement::DefFunction(f) => InnerStatement::DefFunction(def_function(f)),
+        InnerStatement::LetVariable(l) => InnerStatement::LetVariable(let_variable(l)),
+    }
+}
+
+fn def_function(f: DefFunction) -> DefFunction {
+    DefFunction::new(
+        f.name,
+        Signature::new(
+            half_signature(f.signature.positionals),
+            half_signature(f.signature.keywords),
+        ),
+        f.inner_statements.into_iter().map(inner_statement).collect(),
+        expression(f.body),
+    )
+}
+
+fn half_signature(s: HalfSignature) -> HalfSignature {
+    HalfSignature::new(
+        s.requireds,
+        s.optionals
+            .into_iter()
+            .map(|o| OptionalParameter::new(o.name, expression(o.value)))
+            .collect(),
+        s.rest,
+    )
+}
+
+fn let_variable(l: LetVariable) -> LetVariable {
+    LetVariable::new(l.name, expression(l.value))
+}
+
+fn expression(e: Expression) -> Expression {
+    match e {
+        Expression::App(f, a) => Expression::App(Box::new(expression(*f)), arguments(a)),
+        Expression::Dictionary(es) => Expression::Dictionary(
+            es.into_iter()
+                .map(|e| expansion(e, |(k, v)| (expression(k), expression(v))))
+                .collect(),
+        ),
+        Expression::Interpolation(is) => interpolation(is),
+        Expression::List(es) => {
+            Expression::List(es.into_iter().map(|e| expansion(e, expression)).collect())
+        }
+        e => e,
+    }
+}
+
+fn interpolation(is: Vec<Interpolation>) -> Expression {
+    if is.is_empty() {
+        return Expression::String("".into());
+    }
+
+    application(
+        Builtin::Merge,
+        is.into_iter()
+            .map(|i| match i {
+                Interpolation::Expression(e) => application(Builtin::ToString, vec![expression(e)]),
+                Interpolation::String(s) => Expression::String(s),
+            })
+            .collect(),
+    )
+}
+
+fn application(b: Builtin, es: Vec<Expression>) -> Expression {
+    Expression::App(
+        Box::new(Expression::Builtin(b)),
+        Arguments::new(es.into_iter().map(Expansion::Unexpanded).collect(), vec![]),
+    )
+}
+
+fn arguments(a: Arguments) -> Arguments {
+    Arguments::new(
+        a.positionals
+            .into_iter()
+            .map(|e| expansion(e, expression))
+            .collect(),
+        a.keywords
+            .into_iter()
+            .map(|e| expansion(e, |k| KeywordArgument::new(k.name, expression(k.value))))
+            .collect(),
+    )
+}
+
+fn expansion<T>(e: Expansion<T>, f: impl Fn(T) -> T) -> Expansion<T> {
+    match e {
+        Expansion::Expanded(e) => Expansion::Expanded(expression(e)),
+        Expansion::Unexpanded(x) => Expansion::Unexpanded(f(x)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn name(s: &str) -> Expression {
+        Expression::Name(s.into())
+    }
+
+    fn string(s: &str) -> Expression {
+        Expression::String(s.into())
+    }
+
+    fn effect(e: Expression) -> Module {
+        Module::new(vec![], vec![], vec![Statement::Effect(Effect::new(e, false))])
+    }
+
+    #[test]
+    fn desugar_interpolation() {
+        for (e, x) in vec![
+            (Expression::Interpolation(vec![]), string("")),
+            (
+                Expression::Interpolation(vec![Interpolation::String("foo".into())]),
+                application(Builtin::Merge, vec![string("foo")]),
+            ),
+            (
+                Expression::Interpolation(vec![
+                    Interpolation::String("x = ".into()),
+                    Interpolation::Expression(name("x")),
+                    Interpolation::String("!".into()),
+                ]),
+                application(
+                    Builtin::Merge,
+                    vec![
+                        string("x = "),
+                        application(Builtin::ToString, vec![name("x")]),
+                        string("!"),
+                    ],
+                ),
+            ),
+            (
+                Expression::List(vec![Expansion::Unexpanded(Expression::Interpolation(vec![
+                    Interpolation::Expression(Expression::Interpolation(vec![
+                        Interpolation::Expression(name("x")),
+                    ])),
+                ]))]),
+                Expression::List(vec![Expansion::Unexpanded(application(
+                    Builtin::Merge,
+                    vec![application(
+                        Builtin::ToString,
+                        vec![application(
+                            Builtin::Merge,
+                            vec![application(Builtin::ToString, vec![name("x")])],
+                        )],
+                    )],
+                ))]),
+            ),
+        ] {
+            assert_eq!(desugar(effect(e)), Ok(effect(x)));
+        }
+    }
+
+    #[test]
+    fn desugar_interpolation_with_shadowed_names() {
+        let m = |e| {
+            Module::new(
+                vec![],
+                vec![],
+                vec![
+                    Statement::LetVariable(LetVariable::new("merge".into(), name("x"))),
+                    Statement::LetVariable(LetVariable::new("toString".into(), name("x"))),
+                    Statement::Effect(Effect::new(e, false)),
+                ],
+            )
+        };
+
+        assert_eq!(
+            desugar(m(Expression::Interpolation(vec![
+                Interpolation::Expression(name("merge")),
+            ]))),
+            Ok(m(application(
+                Builtin::Merge,
+                vec![application(Builtin::ToString, vec![name("merge")])],
+            )))
+        );
+    }
+
+    #[test]
+    fn desugar_statements() {
+        let i = || Expression::Interpolation(vec![Interpolation::Expression(name("x"))]);
+        let x = || {
+            application(
+                Builtin::Merge,
+                vec![application(Builtin::ToString, vec![name("x")])],
+            )
+        };
+
+        let f = |e: Expression| {
+            DefFunction::new(
+                "f".into(),
+                Signature::new(
+                    HalfSignature::new(
+                        vec![],
+                        vec![OptionalParameter::new("y".into(), e.clone())],
+                        "".into(),
+                    ),
+                    HalfSignature::default(),
+                ),
+                vec![InnerStatement::LetVariable(LetVariable::new(
+                    "z".into(),
+                    e.clone(),
+                ))],
+                e,
+            )
+        };
+
+        assert_eq!(
+            desugar(Module::new(
+                vec![],
+                vec![],
+                vec![
+                    Statement::DefFunction(f(i())),
+                    Statement::LetVariable(LetVariable::new("a".into(), i())),
+                ],
+            )),
+            Ok(Module::new(
+                vec![],
+                vec![],
+                vec![
+                    Statement::DefFunction(f(x())),
+                    Statement::LetVariable(LetVariable::new("a".into(), x())),
+                ],
+            ))
+        );
+    }
 }
@@ -13,6 +13,8 @@ extern crate lazy_static;
 extern crate pest;
 #[macro_use]
 extern crate pest_derive;
+extern crate regex;
+extern crate regex_syntax;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
@@ -46,15 +48,19 @@ const USAGE: &'static str = "
 The interpreter of Flame programming language.
 
 Usage:
-  flame [-h] [<filename>]
+  flame [-h] [--checked] [--seed <seed>] [<filename>]
 
 Options:
-  -h, --help  Show this help.
+  -h, --help     Show this help.
+  --checked      Raise errors on non-finite results of arithmetic.
+  --seed <seed>  Set a seed of random number generation.
 ";
 
 #[derive(Debug, Deserialize)]
 struct Args {
     arg_filename: Option<String>,
+    flag_checked: bool,
+    flag_seed: Option<u64>,
 }
 
 fn main() {
@@ -67,9 +73,14 @@ fn main() {
 fn try_main() -> Result<(), Box<Error>> {
     let args: Args = Docopt::new(USAGE).and_then(|d| d.deserialize())?;
 
-    Ok(block_on(run(compile(desugar(parse::main_module(
-        &read_source(args.arg_filename)?,
-    )?)?)?))?)
+    if let Some(s) = args.flag_seed {
+        builtin::seed_random(s);
+    }
+
+    Ok(block_on(run(compile(
+        desugar(parse::main_module(&read_source(args.arg_filename)?)?)?,
+        args.flag_checked,
+    )?))?)
 }
 
 fn read_source(s: Option<String>) -> Result<String, io::Error> {
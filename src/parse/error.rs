@@ -6,6 +6,12 @@ use pest;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ParsingError(String);
 
+impl ParsingError {
+    pub fn new(s: String) -> Self {
+        ParsingError(s)
+    }
+}
+
 impl Display for ParsingError {
     fn fmt(&self, f: &mut Formatter) -> Result {
         write!(f, "ParsingError: {}", self.0)
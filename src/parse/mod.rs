@@ -1,4 +1,5 @@
 mod error;
 mod parse;
+mod string;
 
 pub use self::parse::main_module;
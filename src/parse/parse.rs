@@ -5,10 +5,12 @@ use pest::Parser;
 
 use super::super::ast::{
     Arguments, DefFunction, Effect, Expansion, Expression, HalfSignature, Import, InnerStatement,
-    KeywordArgument, LetVariable, Module, OptionalParameter, Signature, Statement,
+    Interpolation, KeywordArgument, LetVariable, Module, OptionalParameter, Pragma, Signature,
+    Statement,
 };
 
 use super::error::ParsingError;
+use super::string;
 
 const _GRAMMAR: &'static str = include_str!("grammer.pest");
 
@@ -25,6 +27,7 @@ pub fn sub_module(s: &str) -> Result<Module, ParsingError> {
 }
 
 fn module(r: Rule, s: &str) -> Result<Module, ParsingError> {
+    let mut ps = vec![];
     let mut is = vec![];
     let mut ss = vec![];
 
@@ -32,162 +35,208 @@ fn module(r: Rule, s: &str) -> Result<Module, ParsingError> {
 
     for p in p.into_inner() {
         match p.as_rule() {
-            Rule::import => is.push(import(p)),
-            Rule::statement => ss.push(statement(p)),
-            Rule::inner_statement => ss.push(statement(p)),
+            Rule::pragma => ps.push(pragma(p)),
+            Rule::import => is.push(import(p)?),
+            Rule::statement => ss.push(statement(p)?),
+            Rule::inner_statement => ss.push(statement(p)?),
             _ => unreachable!(),
         }
     }
 
-    Ok(Module::new(is, ss))
+    Ok(Module::new(ps, is, ss))
+}
+
+fn pragma(p: Pair<Rule>) -> Pragma {
+    Pragma::new(p.into_inner().next().unwrap().as_str().into())
 }
 
-fn import(p: Pair<Rule>) -> Import {
-    let s = p.into_inner().next().unwrap().as_str();
-    Import::new(s[1..(s.len() - 1)].into())
+fn import(p: Pair<Rule>) -> Result<Import, ParsingError> {
+    Ok(Import::new(string(p.into_inner().next().unwrap())?))
 }
 
-fn statement(p: Pair<Rule>) -> Statement {
+fn statement(p: Pair<Rule>) -> Result<Statement, ParsingError> {
     let p = p.into_inner().next().unwrap();
 
-    match p.as_rule() {
-        Rule::def_function => Statement::DefFunction(def_function(p)),
-        Rule::effect => Statement::Effect(effect(p)),
-        Rule::let_variable => Statement::LetVariable(let_variable(p)),
+    Ok(match p.as_rule() {
+        Rule::def_function => Statement::DefFunction(def_function(p)?),
+        Rule::effect => Statement::Effect(effect(p)?),
+        Rule::let_variable => Statement::LetVariable(let_variable(p)?),
         _ => unreachable!(),
-    }
+    })
 }
 
-fn effect(p: Pair<Rule>) -> Effect {
+fn effect(p: Pair<Rule>) -> Result<Effect, ParsingError> {
     let b = &p.as_str()[0..2] == "..";
-    Effect::new(expression(p.into_inner().next().unwrap()), b)
+    Ok(Effect::new(expression(p.into_inner().next().unwrap())?, b))
 }
 
-fn expression(p: Pair<Rule>) -> Expression {
+fn expression(p: Pair<Rule>) -> Result<Expression, ParsingError> {
     let p = p.into_inner().next().unwrap();
 
-    match p.as_rule() {
+    Ok(match p.as_rule() {
         Rule::boolean => Expression::Boolean(FromStr::from_str(p.as_str()).unwrap()),
-        Rule::dictionary => dictionary(p),
-        Rule::list => list(p),
+        Rule::bytes => bytes(p)?,
+        Rule::dictionary => dictionary(p)?,
+        Rule::interpolated_string => interpolated_string(p)?,
+        Rule::list => list(p)?,
         Rule::nil => Expression::Nil,
         Rule::number => Expression::Number(FromStr::from_str(p.as_str()).unwrap()),
-        Rule::string => Expression::String({
-            let s = p.as_str();
-
-            s[1..(s.len() - 1)]
-                .replace("\\\"", "\"")
-                .replace("\\\\", "\\")
-                .replace("\\n", "\n")
-                .replace("\\r", "\r")
-                .replace("\\t", "\t")
-                .into()
-        }),
+        Rule::string => Expression::String(string(p)?.into()),
+        Rule::symbol => Expression::Symbol(p.as_str()[1..].into()),
         Rule::name => Expression::Name(p.as_str().into()),
-        Rule::application => application(p),
+        Rule::application => application(p)?,
         _ => unreachable!(),
+    })
+}
+
+fn string(p: Pair<Rule>) -> Result<String, ParsingError> {
+    let s = p.as_str();
+    let (l, c) = p.into_span().start_pos().line_col();
+
+    string::literal(s, l, c)
+}
+
+fn bytes(p: Pair<Rule>) -> Result<Expression, ParsingError> {
+    let s = p.as_str();
+    let (l, c) = p.into_span().start_pos().line_col();
+
+    Ok(Expression::Bytes(string::bytes(s, l, c)?.into()))
+}
+
+fn interpolated_string(p: Pair<Rule>) -> Result<Expression, ParsingError> {
+    let mut is = vec![];
+
+    for p in p.into_inner() {
+        is.push(match p.as_rule() {
+            Rule::interpolated_text => {
+                let s = p.as_str();
+                let (l, c) = p.into_span().start_pos().line_col();
+
+                Interpolation::String(string::interpolated_text(s, l, c)?.into())
+            }
+            Rule::interpolated_expression => {
+                Interpolation::Expression(expression(p.into_inner().next().unwrap())?)
+            }
+            _ => unreachable!(),
+        });
     }
+
+    Ok(Expression::Interpolation(is))
 }
 
-fn dictionary(p: Pair<Rule>) -> Expression {
-    Expression::Dictionary(p.into_inner().map(dictionary_element).collect())
+fn dictionary(p: Pair<Rule>) -> Result<Expression, ParsingError> {
+    Ok(Expression::Dictionary(
+        p.into_inner().map(dictionary_element).collect::<Result<_, _>>()?,
+    ))
 }
 
-fn dictionary_element(p: Pair<Rule>) -> Expansion<(Expression, Expression)> {
-    match p.as_rule() {
+fn dictionary_element(
+    p: Pair<Rule>,
+) -> Result<Expansion<(Expression, Expression)>, ParsingError> {
+    Ok(match p.as_rule() {
         Rule::key_value_pair => {
             let mut i = p.into_inner();
-            Expansion::Unexpanded((expression(i.next().unwrap()), expression(i.next().unwrap())))
+            Expansion::Unexpanded((
+                expression(i.next().unwrap())?,
+                expression(i.next().unwrap())?,
+            ))
         }
         Rule::expanded_expression => {
-            Expansion::Expanded(expression(p.into_inner().next().unwrap()))
+            Expansion::Expanded(expression(p.into_inner().next().unwrap())?)
         }
         _ => unreachable!(),
-    }
+    })
 }
 
-fn list(p: Pair<Rule>) -> Expression {
-    Expression::List(p.into_inner().map(list_element).collect())
+fn list(p: Pair<Rule>) -> Result<Expression, ParsingError> {
+    Ok(Expression::List(
+        p.into_inner().map(list_element).collect::<Result<_, _>>()?,
+    ))
 }
 
-fn list_element(p: Pair<Rule>) -> Expansion<Expression> {
-    match p.as_rule() {
-        Rule::expression => Expansion::Unexpanded(expression(p)),
+fn list_element(p: Pair<Rule>) -> Result<Expansion<Expression>, ParsingError> {
+    Ok(match p.as_rule() {
+        Rule::expression => Expansion::Unexpanded(expression(p)?),
         Rule::expanded_expression => {
-            Expansion::Expanded(expression(p.into_inner().next().unwrap()))
+            Expansion::Expanded(expression(p.into_inner().next().unwrap())?)
         }
         _ => unreachable!(),
-    }
+    })
 }
 
-fn application(p: Pair<Rule>) -> Expression {
+fn application(p: Pair<Rule>) -> Result<Expression, ParsingError> {
     let mut i = p.into_inner();
 
-    Expression::App(
-        Box::new(expression(i.next().unwrap())),
-        arguments(i.next().unwrap()),
-    )
+    Ok(Expression::App(
+        Box::new(expression(i.next().unwrap())?),
+        arguments(i.next().unwrap())?,
+    ))
 }
 
-fn arguments(p: Pair<Rule>) -> Arguments {
+fn arguments(p: Pair<Rule>) -> Result<Arguments, ParsingError> {
     let mut ps = vec![];
     let mut ks = vec![];
 
     for p in p.into_inner() {
         match p.as_rule() {
-            Rule::positional_arguments => ps = positional_arguments(p),
-            Rule::keyword_arguments => ks = keyword_arguments(p),
+            Rule::positional_arguments => ps = positional_arguments(p)?,
+            Rule::keyword_arguments => ks = keyword_arguments(p)?,
             _ => unreachable!(),
         }
     }
 
-    Arguments::new(ps, ks)
+    Ok(Arguments::new(ps, ks))
 }
 
-fn positional_arguments(p: Pair<Rule>) -> Vec<Expansion<Expression>> {
+fn positional_arguments(p: Pair<Rule>) -> Result<Vec<Expansion<Expression>>, ParsingError> {
     p.into_inner()
-        .map(|p| match p.as_rule() {
-            Rule::expression => Expansion::Unexpanded(expression(p)),
-            Rule::expanded_argument => {
-                Expansion::Expanded(expression(p.into_inner().next().unwrap()))
-            }
-
-            _ => unreachable!(),
+        .map(|p| {
+            Ok(match p.as_rule() {
+                Rule::expression => Expansion::Unexpanded(expression(p)?),
+                Rule::expanded_argument => {
+                    Expansion::Expanded(expression(p.into_inner().next().unwrap())?)
+                }
+
+                _ => unreachable!(),
+            })
         })
         .collect()
 }
 
-fn keyword_arguments(p: Pair<Rule>) -> Vec<Expansion<KeywordArgument>> {
+fn keyword_arguments(p: Pair<Rule>) -> Result<Vec<Expansion<KeywordArgument>>, ParsingError> {
     p.into_inner().map(keyword_argument).collect()
 }
 
-fn keyword_argument(p: Pair<Rule>) -> Expansion<KeywordArgument> {
-    match p.as_rule() {
+fn keyword_argument(p: Pair<Rule>) -> Result<Expansion<KeywordArgument>, ParsingError> {
+    Ok(match p.as_rule() {
         Rule::keyword_argument => {
             let mut i = p.into_inner();
 
             Expansion::Unexpanded(KeywordArgument::new(
                 i.next().unwrap().as_str().into(),
-                expression(i.next().unwrap()),
+                expression(i.next().unwrap())?,
             ))
         }
-        Rule::expanded_argument => Expansion::Expanded(expression(p.into_inner().next().unwrap())),
+        Rule::expanded_argument => {
+            Expansion::Expanded(expression(p.into_inner().next().unwrap())?)
+        }
         _ => unreachable!(),
-    }
+    })
 }
 
-fn signature(p: Pair<Rule>) -> Signature {
+fn signature(p: Pair<Rule>) -> Result<Signature, ParsingError> {
     let mut i = p.into_inner();
 
-    Signature::new(
-        half_signature(i.next().unwrap()),
-        i.next()
-            .map(half_signature)
-            .unwrap_or(HalfSignature::default()),
-    )
+    Ok(Signature::new(
+        half_signature(i.next().unwrap())?,
+        match i.next() {
+            Some(p) => half_signature(p)?,
+            None => HalfSignature::default(),
+        },
+    ))
 }
 
-fn half_signature(p: Pair<Rule>) -> HalfSignature {
+fn half_signature(p: Pair<Rule>) -> Result<HalfSignature, ParsingError> {
     let mut rs = vec![];
     let mut os = vec![];
     let mut r = "".into();
@@ -195,61 +244,61 @@ fn half_signature(p: Pair<Rule>) -> HalfSignature {
     for p in p.into_inner() {
         match p.as_rule() {
             Rule::name => rs.push(p.as_str().into()),
-            Rule::optional_parameter => os.push(optional_parameter(p)),
+            Rule::optional_parameter => os.push(optional_parameter(p)?),
             Rule::rest_parameter => r = p.into_inner().next().unwrap().as_str().into(),
             _ => unreachable!(),
         }
     }
 
-    HalfSignature::new(rs, os, r)
+    Ok(HalfSignature::new(rs, os, r))
 }
 
-fn optional_parameter(p: Pair<Rule>) -> OptionalParameter {
+fn optional_parameter(p: Pair<Rule>) -> Result<OptionalParameter, ParsingError> {
     let mut i = p.into_inner();
 
-    OptionalParameter::new(
+    Ok(OptionalParameter::new(
         i.next().unwrap().as_str().into(),
-        expression(i.next().unwrap()),
-    )
+        expression(i.next().unwrap())?,
+    ))
 }
 
-fn def_function(p: Pair<Rule>) -> DefFunction {
+fn def_function(p: Pair<Rule>) -> Result<DefFunction, ParsingError> {
     let mut i = p.into_inner();
 
     let n = i.next().unwrap().as_str().into();
-    let s = signature(i.next().unwrap());
+    let s = signature(i.next().unwrap())?;
 
     let mut ss = vec![];
     let mut b = Expression::Nil;
 
     for p in i {
         match p.as_rule() {
-            Rule::inner_statement => ss.push(inner_statement(p)),
-            Rule::expression => b = expression(p),
+            Rule::inner_statement => ss.push(inner_statement(p)?),
+            Rule::expression => b = expression(p)?,
             _ => unreachable!(),
         }
     }
 
-    DefFunction::new(n, s, ss, b)
+    Ok(DefFunction::new(n, s, ss, b))
 }
 
-fn inner_statement(p: Pair<Rule>) -> InnerStatement {
+fn inner_statement(p: Pair<Rule>) -> Result<InnerStatement, ParsingError> {
     let p = p.into_inner().next().unwrap();
 
-    match p.as_rule() {
-        Rule::def_function => InnerStatement::DefFunction(def_function(p)),
-        Rule::let_variable => InnerStatement::LetVariable(let_variable(p)),
+    Ok(match p.as_rule() {
+        Rule::def_function => InnerStatement::DefFunction(def_function(p)?),
+        Rule::let_variable => InnerStatement::LetVariable(let_variable(p)?),
         _ => unreachable!(),
-    }
+    })
 }
 
-fn let_variable(p: Pair<Rule>) -> LetVariable {
+fn let_variable(p: Pair<Rule>) -> Result<LetVariable, ParsingError> {
     let mut i = p.into_inner();
 
-    LetVariable::new(
+    Ok(LetVariable::new(
         i.next().unwrap().as_str().into(),
-        expression(i.next().unwrap()),
-    )
+        expression(i.next().unwrap())?,
+    ))
 }
 
 #[cfg(test)]
@@ -303,7 +352,7 @@ mod test {
                         .unwrap()
                         .next()
                         .unwrap()
-                ),
+                ).unwrap(),
                 e
             );
         }
@@ -328,7 +377,7 @@ mod test {
                         .unwrap()
                         .next()
                         .unwrap()
-                ),
+                ).unwrap(),
                 e
             );
         }
@@ -359,6 +408,10 @@ mod test {
             "\"\\r\"",
             "\"\\t\"",
             "\"\\\"\\\\\\n\\r\\t\"",
+            "r\"\\d\"",
+            "r#\"\"\"#",
+            "r##\"\"#\"##",
+            "\"\"\"\nfoo\n\"\"\"",
         ] {
             LanguageParser::parse(Rule::string, s).unwrap();
         }
@@ -366,14 +419,143 @@ mod test {
 
     #[test]
     fn escaped_string() {
+        for (s, x) in vec![
+            ("\"\\\"\\\\\\n\\r\\t\"", "\"\\\n\r\t"),
+            ("\"\\\\n\"", "\\n"),
+            ("\"\\u{65e5}\\x41\\0\"", "日A\0"),
+            ("r\"C:\\foo\"", "C:\\foo"),
+            ("r#\"\"\\d+\"\"#", "\"\\d+\""),
+            ("\"\"\"\n  foo\n    \"bar\"\n  \"\"\"", "foo\n  \"bar\""),
+        ] {
+            assert_eq!(
+                expression(
+                    LanguageParser::parse(Rule::expression, s)
+                        .unwrap()
+                        .next()
+                        .unwrap()
+                ).unwrap(),
+                Expression::String(x.into()),
+            );
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        for (s, x) in vec![
+            ("b\"\"", &b""[..]),
+            ("b\"foo\"", &b"foo"[..]),
+            ("b\"\\x00\\xff\\n\"", &b"\x00\xff\n"[..]),
+            ("b\"\\u{e9}\"", &b"\xc3\xa9"[..]),
+        ] {
+            assert_eq!(
+                expression(
+                    LanguageParser::parse(Rule::expression, s)
+                        .unwrap()
+                        .next()
+                        .unwrap()
+                ).unwrap(),
+                Expression::Bytes(x.into()),
+            );
+        }
+
+        assert_eq!(
+            main_module("(write b\"\\xg0\")"),
+            Err(ParsingError::new(
+                "invalid escape sequence \\xg0 at line 1, column 10".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn symbol() {
+        for (s, x) in vec![
+            (":foo", Expression::Symbol("foo".into())),
+            (":foo-bar?", Expression::Symbol("foo-bar?".into())),
+            ("foo:", Expression::Name("foo:".into())),
+        ] {
+            assert_eq!(
+                expression(
+                    LanguageParser::parse(Rule::expression, s)
+                        .unwrap()
+                        .next()
+                        .unwrap()
+                ).unwrap(),
+                x
+            );
+        }
+
+        assert!(LanguageParser::parse(Rule::symbol, ":.foo").is_err());
+        assert!(LanguageParser::parse(Rule::symbol, "::foo").is_err());
+        assert!(LanguageParser::parse(Rule::name, ":foo").is_err());
+    }
+
+    #[test]
+    fn escaped_string_error() {
         assert_eq!(
-            expression(
-                LanguageParser::parse(Rule::expression, "\"\\\"\\\\\\n\\r\\t\"")
-                    .unwrap()
-                    .next()
-                    .unwrap()
+            main_module("42\n  (write \"foo\\q\")"),
+            Err(ParsingError::new(
+                "invalid escape sequence \\q at line 2, column 14".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn interpolated_string() {
+        for (s, x) in vec![
+            ("$\"\"", vec![]),
+            ("$\"foo\"", vec![Interpolation::String("foo".into())]),
+            (
+                "$\"x = { x }, {{y}}\\n\"",
+                vec![
+                    Interpolation::String("x = ".into()),
+                    Interpolation::Expression(Expression::Name("x".into())),
+                    Interpolation::String(", {y}\n".into()),
+                ],
+            ),
+            (
+                "$\"{(f \"}\")}{42}\\u{7b}\"",
+                vec![
+                    Interpolation::Expression(Expression::App(
+                        Box::new(Expression::Name("f".into())),
+                        Arguments::new(
+                            vec![Expansion::Unexpanded(Expression::String("}".into()))],
+                            vec![],
+                        ),
+                    )),
+                    Interpolation::Expression(Expression::Number(42.0)),
+                    Interpolation::String("{".into()),
+                ],
+            ),
+            (
+                "$\"{$\"{x}\"}\"",
+                vec![Interpolation::Expression(Expression::Interpolation(vec![
+                    Interpolation::Expression(Expression::Name("x".into())),
+                ]))],
             ),
-            Expression::String("\"\\\n\r\t".into()),
+        ] {
+            assert_eq!(
+                expression(
+                    LanguageParser::parse(Rule::expression, s)
+                        .unwrap()
+                        .next()
+                        .unwrap()
+                ).unwrap(),
+                Expression::Interpolation(x),
+            );
+        }
+    }
+
+    #[test]
+    fn interpolated_string_error() {
+        for s in &["$\"{\"", "$\"}\"", "$\"{}\"", "$\"{x y}\""] {
+            assert!(main_module(s).is_err());
+        }
+
+        assert_eq!(
+            main_module("(write\n  $\"{x} \\q\")"),
+            Err(ParsingError::new(
+                "invalid escape sequence \\q at line 2, column 9".into()
+            ))
         );
     }
 
@@ -449,7 +631,7 @@ mod test {
                         .unwrap()
                         .next()
                         .unwrap()
-                ),
+                ).unwrap(),
                 e
             );
         }
@@ -506,7 +688,7 @@ mod test {
                         .unwrap()
                         .next()
                         .unwrap()
-                ),
+                ).unwrap(),
                 x
             );
         }
@@ -612,7 +794,7 @@ mod test {
                         .unwrap()
                         .next()
                         .unwrap()
-                ),
+                ).unwrap(),
                 f
             );
         }
@@ -626,6 +808,13 @@ mod test {
         }
     }
 
+    #[test]
+    fn pragma() {
+        for s in &["(pragma checked)", "( pragma\tfoo )"] {
+            LanguageParser::parse(Rule::pragma, s).unwrap();
+        }
+    }
+
     #[test]
     fn import() {
         for s in &["(import \"foo\")", "(import \"x\")"] {
@@ -645,7 +834,7 @@ mod test {
                         .unwrap()
                         .next()
                         .unwrap()
-                ),
+                ).unwrap(),
                 x
             );
         }
@@ -669,10 +858,11 @@ mod test {
     #[test]
     fn main_module_parser() {
         for (s, m) in vec![
-            ("", Module::new(vec![], vec![])),
+            ("", Module::new(vec![], vec![], vec![])),
             (
                 "123",
                 Module::new(
+                    vec![],
                     vec![],
                     vec![Statement::Effect(Effect::new(
                         Expression::Number(123.0),
@@ -683,6 +873,7 @@ mod test {
             (
                 "true nil 123 \"foo\"",
                 Module::new(
+                    vec![],
                     vec![],
                     vec![
                         Statement::Effect(Effect::new(Expression::Boolean(true), false)),
@@ -695,6 +886,7 @@ mod test {
             (
                 " 123 ; foo \n456",
                 Module::new(
+                    vec![],
                     vec![],
                     vec![
                         Statement::Effect(Effect::new(Expression::Number(123.0), false)),
@@ -705,6 +897,7 @@ mod test {
             (
                 "(let name 42)",
                 Module::new(
+                    vec![],
                     vec![],
                     vec![Statement::LetVariable(LetVariable::new(
                         "name".into(),
@@ -715,6 +908,7 @@ mod test {
             (
                 "(def (f) 42)",
                 Module::new(
+                    vec![],
                     vec![],
                     vec![Statement::DefFunction(DefFunction::new(
                         "f".into(),
@@ -726,7 +920,18 @@ mod test {
             ),
             (
                 "(import \"http\")",
-                Module::new(vec![Import::new("http".into())], vec![]),
+                Module::new(vec![], vec![Import::new("http".into())], vec![]),
+            ),
+            (
+                "(pragma checked) (import \"http\") 42",
+                Module::new(
+                    vec![Pragma::new("checked".into())],
+                    vec![Import::new("http".into())],
+                    vec![Statement::Effect(Effect::new(
+                        Expression::Number(42.0),
+                        false,
+                    ))],
+                ),
             ),
         ] {
             println!("{:?}", s);
@@ -738,10 +943,11 @@ mod test {
     #[test]
     fn sub_module_parser() {
         for (s, m) in vec![
-            ("", Module::new(vec![], vec![])),
+            ("", Module::new(vec![], vec![], vec![])),
             (
                 "(let name 42)",
                 Module::new(
+                    vec![],
                     vec![],
                     vec![Statement::LetVariable(LetVariable::new(
                         "name".into(),
@@ -752,6 +958,7 @@ mod test {
             (
                 "(def (f) 42)",
                 Module::new(
+                    vec![],
                     vec![],
                     vec![Statement::DefFunction(DefFunction::new(
                         "f".into(),
@@ -763,7 +970,7 @@ mod test {
             ),
             (
                 "(import \"http\")",
-                Module::new(vec![Import::new("http".into())], vec![]),
+                Module::new(vec![], vec![Import::new("http".into())], vec![]),
             ),
         ] {
             println!("{:?}", s);
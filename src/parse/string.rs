@@ -0,0 +1,260 @@
+use std::char;
+
+use super::error::ParsingError;
+
+pub fn literal(s: &str, l: usize, c: usize) -> Result<String, ParsingError> {
+    if s.starts_with('r') {
+        let n = s.find('"').unwrap();
+        Ok(s[(n + 1)..(s.len() - n)].into())
+    } else if s.starts_with("\"\"\"") {
+        multi_line(&s[3..(s.len() - 3)], l, c + 3)
+    } else {
+        unescape(&s[1..(s.len() - 1)], l, c + 1, false)
+    }
+}
+
+pub fn bytes(s: &str, l: usize, c: usize) -> Result<Vec<u8>, ParsingError> {
+    decode(&s[2..(s.len() - 1)], l, c + 2, false, true)
+}
+
+pub fn interpolated_text(s: &str, l: usize, c: usize) -> Result<String, ParsingError> {
+    unescape(s, l, c, true)
+}
+
+fn multi_line(s: &str, l: usize, c: usize) -> Result<String, ParsingError> {
+    if !s.starts_with('\n') {
+        return unescape(s, l, c, false);
+    }
+
+    let mut ls: Vec<&str> = s[1..].split('\n').collect();
+
+    if ls.last().map(|l| is_blank(l)).unwrap_or(false) {
+        ls.pop();
+    }
+
+    let n = ls
+        .iter()
+        .filter(|l| !is_blank(l))
+        .map(|l| l.len() - l.trim_left_matches(|c| c == ' ' || c == '\t').len())
+        .min()
+        .unwrap_or(0);
+
+    let mut ss = vec![];
+
+    for (i, s) in ls.iter().enumerate() {
+        let m = n.min(s.len());
+        ss.push(unescape(&s[m..], l + i + 1, m + 1, false)?);
+    }
+
+    Ok(ss.join("\n"))
+}
+
+fn is_blank(s: &str) -> bool {
+    s.chars().all(|c| c == ' ' || c == '\t')
+}
+
+fn unescape(s: &str, l: usize, c: usize, b: bool) -> Result<String, ParsingError> {
+    // Only byte escapes can produce invalid UTF-8.
+    Ok(String::from_utf8(decode(s, l, c, b, false)?).unwrap())
+}
+
+fn decode(s: &str, mut l: usize, mut c: usize, b: bool, r: bool) -> Result<Vec<u8>, ParsingError> {
+    let mut t = Vec::with_capacity(s.len());
+    let mut i = 0;
+
+    while let Some(x) = s[i..].chars().next() {
+        if b && (x == '{' || x == '}') {
+            t.push(x as u8);
+            c += 2;
+            i += 2;
+        } else if r && s[i..].starts_with("\\x") {
+            let n = s
+                .get((i + 2)..(i + 4))
+                .and_then(hex)
+                .ok_or_else(|| invalid_escape(&s[i..], l, c))?;
+
+            t.push(n as u8);
+            c += 4;
+            i += 4;
+        } else if x == '\\' {
+            let (y, n) = escape(&s[i..]).ok_or_else(|| invalid_escape(&s[i..], l, c))?;
+
+            push(&mut t, y);
+            c += s[i..(i + n)].chars().count();
+            i += n;
+        } else {
+            push(&mut t, x);
+            i += x.len_utf8();
+
+            if x == '\n' {
+                l += 1;
+                c = 1;
+            } else {
+                c += 1;
+            }
+        }
+    }
+
+    Ok(t)
+}
+
+fn push(bs: &mut Vec<u8>, c: char) {
+    let mut b = [0; 4];
+    bs.extend_from_slice(c.encode_utf8(&mut b).as_bytes());
+}
+
+fn escape(s: &str) -> Option<(char, usize)> {
+    Some(match s[1..].chars().next()? {
+        '"' => ('"', 2),
+        '\\' => ('\\', 2),
+        'n' => ('\n', 2),
+        'r' => ('\r', 2),
+        't' => ('\t', 2),
+        '0' => ('\0', 2),
+        'x' => {
+            let n = hex(s.get(2..4)?)?;
+
+            if n > 0x7f {
+                return None;
+            }
+
+            (n as u8 as char, 4)
+        }
+        'u' => {
+            if !s[2..].starts_with('{') {
+                return None;
+            }
+
+            let j = s.find('}')?;
+            let h = &s[3..j];
+
+            if h.is_empty() || h.len() > 6 {
+                return None;
+            }
+
+            (char::from_u32(hex(h)?)?, j + 1)
+        }
+        _ => return None,
+    })
+}
+
+fn hex(s: &str) -> Option<u32> {
+    if s.chars().all(|c| c.is_digit(16)) {
+        u32::from_str_radix(s, 16).ok()
+    } else {
+        None
+    }
+}
+
+fn invalid_escape(s: &str, l: usize, c: usize) -> ParsingError {
+    let n = match s[1..].chars().next() {
+        Some('u') => s.find('}').map(|j| j + 1).unwrap_or(2),
+        Some('x') => 4,
+        _ => 2,
+    };
+
+    let e: String = s.chars().take(n).collect();
+
+    ParsingError::new(format!(
+        "invalid escape sequence {} at line {}, column {}",
+        e, l, c
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unescape() {
+        for (s, x) in vec![
+            ("", ""),
+            ("foo", "foo"),
+            ("\\\"\\\\\\n\\r\\t\\0", "\"\\\n\r\t\0"),
+            ("\\\\n", "\\n"),
+            ("\\\\\\n", "\\\n"),
+            ("\\x41\\x7f", "A\x7f"),
+            ("\\u{41}\\u{e9}\\u{65e5}\\u{1F44D}", "Aé日👍"),
+            ("日本\\t語", "日本\t語"),
+        ] {
+            assert_eq!(super::unescape(s, 1, 1, false), Ok(x.into()));
+        }
+    }
+
+    #[test]
+    fn unescape_error() {
+        for (s, l, c, x) in vec![
+            ("\\q", 1, 2, "\\q at line 1, column 2"),
+            ("foo\\q", 1, 2, "\\q at line 1, column 5"),
+            ("日本\\q", 1, 1, "\\q at line 1, column 3"),
+            ("a\nb\\q", 3, 4, "\\q at line 4, column 2"),
+            ("\\x80", 1, 1, "\\x80 at line 1, column 1"),
+            ("\\xg0", 1, 1, "\\xg0 at line 1, column 1"),
+            ("\\u{110000}", 1, 1, "\\u{110000} at line 1, column 1"),
+            ("\\u{d800}", 1, 1, "\\u{d800} at line 1, column 1"),
+            ("\\u{}", 1, 1, "\\u{} at line 1, column 1"),
+            ("\\u41", 1, 1, "\\u at line 1, column 1"),
+        ] {
+            assert_eq!(
+                super::unescape(s, l, c, false),
+                Err(ParsingError::new(format!("invalid escape sequence {}", x)))
+            );
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        for (s, x) in vec![
+            ("b\"\"", &b""[..]),
+            ("b\"foo\\n\"", &b"foo\n"[..]),
+            ("b\"\\x00\\x80\\xff\"", &b"\x00\x80\xff"[..]),
+            ("b\"日\"", &b"\xe6\x97\xa5"[..]),
+        ] {
+            assert_eq!(super::bytes(s, 1, 1), Ok(x.into()));
+        }
+
+        assert_eq!(
+            super::bytes("b\"foo\\x1\"", 1, 1),
+            Err(ParsingError::new(
+                "invalid escape sequence \\x1 at line 1, column 6".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn interpolated_text() {
+        for (s, x) in vec![
+            ("foo", "foo"),
+            ("{{foo}}", "{foo}"),
+            ("\\u{7b}}}\\n", "{}\n"),
+        ] {
+            assert_eq!(super::interpolated_text(s, 1, 1), Ok(x.into()));
+        }
+    }
+
+    #[test]
+    fn literal() {
+        for (s, x) in vec![
+            ("\"foo\\n\"", "foo\n"),
+            ("r\"C:\\foo\\bar\"", "C:\\foo\\bar"),
+            ("r#\"\"quoted\" \\d+\"#", "\"quoted\" \\d+"),
+            ("r##\"\"#\"##", "\"#"),
+            ("\"\"\"foo \"bar\" baz\"\"\"", "foo \"bar\" baz"),
+            ("\"\"\"\n    foo\n      bar\n    \"\"\"", "foo\n  bar"),
+            ("\"\"\"\n  foo\n\n  bar\\t\n  \"\"\"", "foo\n\nbar\t"),
+            ("\"\"\"\n  foo\n  bar\"\"\"", "foo\nbar"),
+        ] {
+            assert_eq!(literal(s, 1, 1), Ok(x.into()));
+        }
+    }
+
+    #[test]
+    fn literal_error() {
+        assert_eq!(
+            literal("\"\"\"\n  foo\n  b\\qr\n  \"\"\"", 1, 1),
+            Err(ParsingError::new(
+                "invalid escape sequence \\q at line 3, column 4".into()
+            ))
+        );
+    }
+}